@@ -0,0 +1,178 @@
+//! Centroidal Voronoi tessellation (Lloyd relaxation) post-processor for point sets in
+//! `[0, 1]^D` or on the unit `D`-sphere.
+//!
+//! Low-discrepancy generators already spread points out fairly evenly, but Lloyd
+//! relaxation pulls a fixed-size point set further towards a centroidal Voronoi
+//! tessellation (each point at the centroid of the region closest to it), which gives
+//! very uniform coverage at the cost of iterating. This module reuses [`HaltonConst`]
+//! from [`crate::lds`] as the quasi-random sampler that drives the relaxation.
+
+use crate::lds::{HaltonConst, PRIME_TABLE};
+use crate::mathops::sqrt;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// The domain a [`Cvt`] relaxes its generators within.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Domain {
+    /// The unit square `[0, 1]^D`.
+    UnitSquare,
+    /// The unit `D`-sphere (points are kept at unit norm).
+    UnitSphere,
+}
+
+fn dist_sq<const D: usize>(a: &[f64; D], b: &[f64; D]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+fn normalize<const D: usize>(v: &mut [f64; D]) {
+    let norm = sqrt(v.iter().map(|x| x * x).sum());
+    if norm > 0.0 {
+        for x in v.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
+/// Lloyd-relaxation (centroidal Voronoi tessellation) post-processor.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::cvt::{Cvt, Domain};
+///
+/// let generators = vec![[0.25, 0.25], [0.75, 0.75]];
+/// let mut cvt = Cvt::new(generators, Domain::UnitSquare);
+/// let relaxed = cvt.relax(5, 200, 50);
+/// assert_eq!(relaxed.len(), 2);
+/// ```
+pub struct Cvt<const D: usize> {
+    generators: Vec<[f64; D]>,
+    domain: Domain,
+    sampler: HaltonConst<D>,
+}
+
+impl<const D: usize> Cvt<D> {
+    /// Creates a new [`Cvt`] seeded with an initial set of `generators` (e.g. drawn from
+    /// [`crate::Halton`], [`crate::Sphere`], or [`crate::sphere_n::SphereSpiral`]) to be
+    /// relaxed within `domain`.
+    pub fn new(generators: Vec<[f64; D]>, domain: Domain) -> Self {
+        let bases: [usize; D] = core::array::from_fn(|i| PRIME_TABLE[i]);
+        Self {
+            generators,
+            domain,
+            sampler: HaltonConst::new(bases),
+        }
+    }
+
+    /// Draws the next quasi-random sample point from the configured domain.
+    fn next_sample(&mut self) -> [f64; D] {
+        let raw = self.sampler.pop(); // lands in [0, 1]^D
+        match self.domain {
+            Domain::UnitSquare => raw,
+            Domain::UnitSphere => {
+                let mut v: [f64; D] = core::array::from_fn(|i| 2.0 * raw[i] - 1.0);
+                normalize(&mut v);
+                v
+            }
+        }
+    }
+
+    /// Relaxes the generators towards a centroidal Voronoi tessellation for `it_max`
+    /// Lloyd iterations, each estimated from `sample_num` quasi-random samples processed
+    /// in batches of `sample_batch` (so large `sample_num` doesn't require materializing
+    /// every sample at once). Returns the relaxed generator positions.
+    pub fn relax(&mut self, it_max: usize, sample_num: usize, sample_batch: usize) -> Vec<[f64; D]> {
+        let k = self.generators.len();
+
+        for _ in 0..it_max {
+            let mut sums = vec![[0.0f64; D]; k];
+            let mut counts = vec![0usize; k];
+
+            let mut sampled = 0;
+            while sampled < sample_num {
+                let batch = sample_batch.min(sample_num - sampled);
+                for _ in 0..batch {
+                    let sample = self.next_sample();
+                    let nearest = self
+                        .generators
+                        .iter()
+                        .enumerate()
+                        .map(|(i, g)| (i, dist_sq(g, &sample)))
+                        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                        .map(|(i, _)| i)
+                        .unwrap();
+
+                    for d in 0..D {
+                        sums[nearest][d] += sample[d];
+                    }
+                    counts[nearest] += 1;
+                }
+                sampled += batch;
+            }
+
+            for i in 0..k {
+                if counts[i] == 0 {
+                    continue; // no samples landed in this cell this iteration; keep the generator in place
+                }
+                let mut centroid: [f64; D] = core::array::from_fn(|d| sums[i][d] / counts[i] as f64);
+                if self.domain == Domain::UnitSphere {
+                    normalize(&mut centroid);
+                }
+                self.generators[i] = centroid;
+            }
+        }
+
+        self.generators.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cvt_unit_square_stays_in_bounds() {
+        let generators = vec![[0.2, 0.2], [0.5, 0.5], [0.8, 0.8]];
+        let mut cvt = Cvt::new(generators, Domain::UnitSquare);
+        let relaxed = cvt.relax(3, 300, 100);
+        assert_eq!(relaxed.len(), 3);
+        for p in &relaxed {
+            for &x in p {
+                assert!((0.0..=1.0).contains(&x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cvt_unit_sphere_stays_unit_norm() {
+        let generators = vec![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let mut cvt = Cvt::new(generators, Domain::UnitSphere);
+        let relaxed = cvt.relax(3, 300, 100);
+        assert_eq!(relaxed.len(), 3);
+        for p in &relaxed {
+            let norm_sq: f64 = p.iter().map(|x| x * x).sum();
+            assert!((norm_sq - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_cvt_relax_moves_generators() {
+        let generators = vec![[0.1, 0.1], [0.9, 0.9]];
+        let mut cvt = Cvt::new(generators.clone(), Domain::UnitSquare);
+        let relaxed = cvt.relax(5, 500, 100);
+        assert_ne!(relaxed, generators);
+    }
+
+    #[test]
+    fn test_cvt_zero_iterations_is_noop() {
+        let generators = vec![[0.3, 0.3], [0.6, 0.6]];
+        let mut cvt = Cvt::new(generators.clone(), Domain::UnitSquare);
+        let relaxed = cvt.relax(0, 100, 50);
+        assert_eq!(relaxed, generators);
+    }
+}
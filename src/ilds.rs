@@ -6,6 +6,8 @@
 //! which can be useful for various applications like sampling, optimization,
 //! or numerical integration.
 
+use crate::splitmix::{mix64, permute_digit_u32};
+
 /// Integer Van der Corput sequence generator
 ///
 /// Generates integer values of the Van der Corput sequence with a specified scale.
@@ -13,11 +15,12 @@
 /// # Examples
 ///
 /// ```
-/// use lds_gen::ilds::VdCorput;
+/// use lds_rs::ilds::VdCorput;
 /// let mut vdc = VdCorput::new(2, 10);
 /// vdc.reseed(0);
 /// assert_eq!(vdc.pop(), 512); // 0.5 * 2^10 = 512
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VdCorput {
     base: u32,
     #[allow(dead_code)] // Used for documentation and API consistency
@@ -70,6 +73,53 @@ impl VdCorput {
     pub fn reseed(&mut self, seed: u32) {
         self.count = seed;
     }
+
+    /// Returns the current position (the counter) of this [`VdCorput`].
+    ///
+    /// Together with the generator's configuration (captured via `serde` when the
+    /// `serde` feature is enabled), this is enough to checkpoint and resume a long or
+    /// distributed QMC run, or to shard the index space across machines.
+    pub fn position(&self) -> u64 {
+        self.count as u64
+    }
+
+    /// Sets the current position (the counter) of this [`VdCorput`] directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` doesn't fit in the `u32` counter (e.g. a sharding coordinator handed
+    /// out an out-of-range shard start) instead of silently truncating it.
+    pub fn set_position(&mut self, n: u64) {
+        self.count = u32::try_from(n).expect("VdCorput: position out of range for u32 counter");
+    }
+
+    /// Reconstructs the current value as an `f64` in `[0, 1)` via Kahan (compensated)
+    /// summation over each digit's contribution, instead of one large integer divide
+    /// (e.g. `pop() as f64 / base.pow(scale) as f64`).
+    ///
+    /// At large `scale`, that naive divide loses low-order bits as digit contributions
+    /// accumulate, so deep sequences drift from the true radical inverse; summing the
+    /// per-digit contributions with Kahan compensation keeps the running error bounded
+    /// instead of growing with the digit count, which matters for long Monte Carlo
+    /// integrations.
+    pub fn as_f64_compensated(&self) -> f64 {
+        let mut k = self.count;
+        let mut sum = 0.0;
+        let mut c = 0.0;
+        let mut denom = 1.0;
+
+        while k != 0 {
+            let remainder = k % self.base;
+            denom *= self.base as f64;
+            k /= self.base;
+
+            let y = remainder as f64 / denom - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+        }
+        sum
+    }
 }
 
 impl Default for VdCorput {
@@ -78,6 +128,172 @@ impl Default for VdCorput {
     }
 }
 
+/// Integer Van der Corput sequence generator with a 64-bit backing
+///
+/// Mirrors [`VdCorput`], but computes everything in `u64` so scales and bases that
+/// would silently overflow `u32` (e.g. `base.pow(scale) >= 2^32`, which `VdCorput`
+/// quietly wraps instead of reporting) stay representable. `new` panics with a clear
+/// message instead of silently wrapping if `base^scale` still doesn't fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::ilds::VdCorput64;
+/// let mut vdc = VdCorput64::new(2, 40);
+/// vdc.reseed(0);
+/// assert_eq!(vdc.pop(), 1u64 << 39); // 0.5 * 2^40
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VdCorput64 {
+    base: u64,
+    #[allow(dead_code)] // Used for documentation and API consistency
+    scale: u32,
+    count: u64,
+    factor: u64,
+}
+
+impl VdCorput64 {
+    /// Creates a new 64-bit integer Van der Corput sequence generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the number system
+    /// * `scale` - The scale factor determining the number of digits that can be represented
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base.pow(scale)` would overflow `u64`.
+    pub fn new(base: u64, scale: u32) -> Self {
+        let factor = base
+            .checked_pow(scale)
+            .expect("VdCorput64: base^scale overflows u64; reduce base or scale");
+        Self {
+            base,
+            scale,
+            count: 0,
+            factor,
+        }
+    }
+
+    /// Generates the next integer value in the sequence
+    pub fn pop(&mut self) -> u64 {
+        self.count += 1;
+        let mut k = self.count;
+        let mut vdc = 0;
+        let mut factor = self.factor;
+
+        while k != 0 {
+            factor /= self.base;
+            let remainder = k % self.base;
+            k /= self.base;
+            vdc += remainder * factor;
+        }
+        vdc
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed value that determines the starting point of the sequence generation
+    pub fn reseed(&mut self, seed: u64) {
+        self.count = seed;
+    }
+
+    /// Returns the current position (the counter) of this [`VdCorput64`].
+    pub fn position(&self) -> u64 {
+        self.count
+    }
+
+    /// Sets the current position (the counter) of this [`VdCorput64`] directly.
+    pub fn set_position(&mut self, n: u64) {
+        self.count = n;
+    }
+}
+
+impl Default for VdCorput64 {
+    fn default() -> Self {
+        Self::new(2, 10)
+    }
+}
+
+/// Allows a [`VdCorput64`] to be used as a standard Rust iterator. The sequence is
+/// infinite, so `next()` always returns `Some`.
+impl Iterator for VdCorput64 {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        Some(self.pop())
+    }
+}
+
+/// Integer Halton sequence generator with a 64-bit backing
+///
+/// Mirrors [`Halton`], built from two [`VdCorput64`] generators so deep sequences with
+/// large bases/scales stay representable instead of silently wrapping in `u32`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Halton64 {
+    vdc0: VdCorput64,
+    vdc1: VdCorput64,
+}
+
+impl Halton64 {
+    /// Creates a new 64-bit integer Halton sequence generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The bases of the two coordinates
+    /// * `scale` - The scale factors of the two coordinates
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `base[i].pow(scale[i])` would overflow `u64`.
+    pub fn new(base: [u64; 2], scale: [u32; 2]) -> Self {
+        Self {
+            vdc0: VdCorput64::new(base[0], scale[0]),
+            vdc1: VdCorput64::new(base[1], scale[1]),
+        }
+    }
+
+    /// Generates the next pair of integer values in the sequence
+    pub fn pop(&mut self) -> [u64; 2] {
+        [self.vdc0.pop(), self.vdc1.pop()]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: u64) {
+        self.vdc0.reseed(seed);
+        self.vdc1.reseed(seed);
+    }
+
+    /// Returns the current position (the counter) of this [`Halton64`].
+    pub fn position(&self) -> u64 {
+        self.vdc0.position()
+    }
+
+    /// Sets the current position (the counter) of this [`Halton64`] directly.
+    pub fn set_position(&mut self, n: u64) {
+        self.vdc0.set_position(n);
+        self.vdc1.set_position(n);
+    }
+}
+
+impl Default for Halton64 {
+    fn default() -> Self {
+        Self::new([2, 3], [10, 10])
+    }
+}
+
+/// Allows a [`Halton64`] to be used as a standard Rust iterator. The sequence is
+/// infinite, so `next()` always returns `Some`.
+impl Iterator for Halton64 {
+    type Item = [u64; 2];
+
+    fn next(&mut self) -> Option<[u64; 2]> {
+        Some(self.pop())
+    }
+}
+
 /// Integer Halton sequence generator
 ///
 /// Generates points in a 2-dimensional space using integer Halton sequences.
@@ -85,13 +301,14 @@ impl Default for VdCorput {
 /// # Examples
 ///
 /// ```
-/// use lds_gen::ilds::Halton;
+/// use lds_rs::ilds::Halton;
 /// let mut hgen = Halton::new([2, 3], [11, 7]);
 /// hgen.reseed(0);
 /// let res = hgen.pop();
 /// assert_eq!(res[0], 1024); // 0.5 * 2^11 = 1024
 /// assert_eq!(res[1], 729);  // 1/3 * 3^7 = 729
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Halton {
     vdc0: VdCorput,
     vdc1: VdCorput,
@@ -127,6 +344,343 @@ impl Halton {
         self.vdc0.reseed(seed);
         self.vdc1.reseed(seed);
     }
+
+    /// Returns the current position (the shared counter) of this [`Halton`]; see
+    /// [`VdCorput::position`].
+    pub fn position(&self) -> u64 {
+        self.vdc0.position()
+    }
+
+    /// Sets the current position (the counter) of both dimensions' generators directly.
+    pub fn set_position(&mut self, n: u64) {
+        self.vdc0.set_position(n);
+        self.vdc1.set_position(n);
+    }
+
+    /// Reconstructs the current point as `[f64; 2]` via Kahan compensated summation in
+    /// each dimension; see [`VdCorput::as_f64_compensated`].
+    pub fn as_f64_compensated(&self) -> [f64; 2] {
+        [
+            self.vdc0.as_f64_compensated(),
+            self.vdc1.as_f64_compensated(),
+        ]
+    }
+}
+
+/// Allows a [`VdCorput`] to be used as a standard Rust iterator, e.g.
+/// `vdc.take(5).collect::<Vec<_>>()`. The sequence is infinite, so `next()` always
+/// returns `Some`.
+impl Iterator for VdCorput {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        Some(self.pop())
+    }
+}
+
+/// Allows a [`Halton`] to be used as a standard Rust iterator, e.g.
+/// `halton.take(5).collect::<Vec<_>>()`. The sequence is infinite, so `next()` always
+/// returns `Some`.
+impl Iterator for Halton {
+    type Item = [u32; 2];
+
+    fn next(&mut self) -> Option<[u32; 2]> {
+        Some(self.pop())
+    }
+}
+
+/// The digit-scrambling strategy used by [`ScrambledVdCorput`] / [`ScrambledHalton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntScrambleMode {
+    /// A single fixed permutation of `{0, .., base - 1}`, applied to every digit
+    /// regardless of the higher-order digits already seen.
+    Flat,
+    /// Owen-style nested scrambling: the permutation applied to digit `d_i` depends on
+    /// the higher-order digits `d_0..d_{i-1}` already seen, via a seeded hash of that
+    /// prefix. Decorrelates dimensions far better than a single flat permutation.
+    Owen,
+}
+
+/// Integer Van der Corput sequence generator with digit scrambling and optional leaping
+///
+/// The plain [`VdCorput`] sequence degrades for large bases: consecutive points become
+/// visibly correlated, which hurts the evenly-distributed property Monte Carlo users
+/// rely on. `ScrambledVdCorput` permutes each base-`b` digit before weighting it (see
+/// [`IntScrambleMode`]), and can optionally "leap" by taking every `L`-th index instead of
+/// every index, both of which decorrelate the sequence while preserving its
+/// low-discrepancy structure.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::ilds::{ScrambledVdCorput, IntScrambleMode};
+/// let mut vdc = ScrambledVdCorput::new(2, 10, 42);
+/// vdc.reseed(0);
+/// let result = vdc.pop();
+/// assert!(result < 1024);
+/// ```
+pub struct ScrambledVdCorput {
+    base: u32,
+    #[allow(dead_code)] // Used for documentation and API consistency
+    scale: u32,
+    count: u32,
+    factor: u32,
+    key: u64,
+    mode: IntScrambleMode,
+    leap: u32,
+}
+
+impl ScrambledVdCorput {
+    /// Creates a new [`ScrambledVdCorput`] with Owen (nested) scrambling and no leaping
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the number system
+    /// * `scale` - The scale factor determining the number of digits that can be represented
+    /// * `seed` - The RNG seed that determines this generator's scrambling key; different
+    ///   seeds give independent, decorrelated realizations of the same base
+    pub fn new(base: u32, scale: u32, seed: u64) -> Self {
+        Self::with_options(base, scale, seed, IntScrambleMode::Owen, 1)
+    }
+
+    /// Creates a new [`ScrambledVdCorput`] with an explicit [`IntScrambleMode`] and leap
+    ///
+    /// # Arguments
+    ///
+    /// * `leap` - Step size between successive counter values; `pop()` advances the
+    ///   counter by `leap` instead of `1`. A `leap` of `0` is treated as `1`.
+    pub fn with_options(base: u32, scale: u32, seed: u64, mode: IntScrambleMode, leap: u32) -> Self {
+        let factor = base.pow(scale);
+        Self {
+            base,
+            scale,
+            count: 0,
+            factor,
+            key: mix64(seed),
+            mode,
+            leap: leap.max(1),
+        }
+    }
+
+    /// Generates the next integer value in the scrambled sequence
+    pub fn pop(&mut self) -> u32 {
+        self.count += self.leap;
+        self.pop_at(self.count)
+    }
+
+    /// Computes the scrambled value the sequence would produce for counter `n`,
+    /// without mutating the generator
+    pub fn pop_at(&self, n: u32) -> u32 {
+        let mut k = n;
+        let mut vdc = 0;
+        let mut factor = self.factor;
+        let mut prefix_key = self.key;
+
+        while k != 0 {
+            factor /= self.base;
+            let digit = k % self.base;
+            k /= self.base;
+
+            let scrambled = match self.mode {
+                IntScrambleMode::Flat => permute_digit_u32(digit, self.base, self.key),
+                IntScrambleMode::Owen => {
+                    let p = permute_digit_u32(digit, self.base, prefix_key);
+                    prefix_key =
+                        mix64(prefix_key ^ (digit as u64).wrapping_add(0x9E37_79B9_7F4A_7C15));
+                    p
+                }
+            };
+
+            vdc += scrambled * factor;
+        }
+        vdc
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed value that determines the starting point of the sequence generation
+    pub fn reseed(&mut self, seed: u32) {
+        self.count = seed;
+    }
+}
+
+/// Allows a [`ScrambledVdCorput`] to be used as a standard Rust iterator.
+impl Iterator for ScrambledVdCorput {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        Some(self.pop())
+    }
+}
+
+/// Integer Halton sequence generator with digit scrambling and optional leaping
+///
+/// Combines two [`ScrambledVdCorput`] generators, one per dimension, each with an
+/// independent scrambling key derived from `seed`. See [`ScrambledVdCorput`] for why
+/// this fixes the correlation the plain [`Halton`] shows for larger bases.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::ilds::ScrambledHalton;
+/// let mut hgen = ScrambledHalton::new([2, 3], [11, 7], 42);
+/// hgen.reseed(0);
+/// let res = hgen.pop();
+/// assert!(res[0] < 2048);
+/// assert!(res[1] < 2187);
+/// ```
+pub struct ScrambledHalton {
+    vdc0: ScrambledVdCorput,
+    vdc1: ScrambledVdCorput,
+}
+
+impl ScrambledHalton {
+    /// Creates a new [`ScrambledHalton`] with Owen (nested) scrambling and no leaping
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - An array of two integers used as bases for generating the sequence
+    /// * `scale` - An array of two integers used as scales for each dimension
+    /// * `seed` - The RNG seed that determines the scrambling keys for both dimensions
+    pub fn new(base: [u32; 2], scale: [u32; 2], seed: u64) -> Self {
+        Self {
+            vdc0: ScrambledVdCorput::new(base[0], scale[0], seed),
+            vdc1: ScrambledVdCorput::new(base[1], scale[1], mix64(seed)),
+        }
+    }
+
+    /// Generates the next point in the scrambled integer Halton sequence
+    pub fn pop(&mut self) -> [u32; 2] {
+        [self.vdc0.pop(), self.vdc1.pop()]
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed value that determines the starting point of the sequence generation
+    pub fn reseed(&mut self, seed: u32) {
+        self.vdc0.reseed(seed);
+        self.vdc1.reseed(seed);
+    }
+}
+
+/// Allows a [`ScrambledHalton`] to be used as a standard Rust iterator.
+impl Iterator for ScrambledHalton {
+    type Item = [u32; 2];
+
+    fn next(&mut self) -> Option<[u32; 2]> {
+        Some(self.pop())
+    }
+}
+
+/// A [`VdCorput`] wrapped so it can be used as a `rand` [`Distribution<f64>`](rand::distributions::Distribution),
+/// for dropping a low-discrepancy sequence in wherever `rand`-based Monte Carlo code
+/// expects a sampler.
+///
+/// `Distribution::sample` takes `&self`, but popping a value is inherently stateful, so
+/// the generator is held behind a `RefCell`. The fractional value is derived the same
+/// way `rand`'s uniform-float path does: `raw / base^scale`.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::ilds::VdCorputDistribution;
+/// use rand::distributions::Distribution;
+///
+/// let dist = VdCorputDistribution::new(2, 10);
+/// dist.reseed(0);
+/// let mut rng = rand::thread_rng();
+/// let result: f64 = dist.sample(&mut rng);
+/// assert_eq!(result, 0.5);
+/// ```
+#[cfg(feature = "rand")]
+pub struct VdCorputDistribution {
+    inner: core::cell::RefCell<VdCorput>,
+}
+
+#[cfg(feature = "rand")]
+impl VdCorputDistribution {
+    /// Creates a new [`VdCorputDistribution`] wrapping a fresh [`VdCorput`]
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base of the number system
+    /// * `scale` - The scale factor determining the number of digits that can be represented
+    pub fn new(base: u32, scale: u32) -> Self {
+        Self {
+            inner: core::cell::RefCell::new(VdCorput::new(base, scale)),
+        }
+    }
+
+    /// Resets the state of the underlying sequence generator to a specific seed value
+    pub fn reseed(&self, seed: u32) {
+        self.inner.borrow_mut().reseed(seed);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<f64> for VdCorputDistribution {
+    fn sample<R: rand::Rng + ?Sized>(&self, _rng: &mut R) -> f64 {
+        let mut vdc = self.inner.borrow_mut();
+        let raw = vdc.pop();
+        raw as f64 / vdc.factor as f64
+    }
+}
+
+/// A [`Halton`] wrapped so it can be used as a `rand` [`Distribution<[f64; 2]>`](rand::distributions::Distribution),
+/// for dropping a low-discrepancy point sequence wherever `rand`-based Monte Carlo
+/// code expects a sampler. See [`VdCorputDistribution`] for why a `RefCell` is needed.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::ilds::HaltonDistribution;
+/// use rand::distributions::Distribution;
+///
+/// let dist = HaltonDistribution::new([2, 3], [11, 7]);
+/// dist.reseed(0);
+/// let mut rng = rand::thread_rng();
+/// let result: [f64; 2] = dist.sample(&mut rng);
+/// assert_eq!(result[0], 0.5);
+/// ```
+#[cfg(feature = "rand")]
+pub struct HaltonDistribution {
+    inner: core::cell::RefCell<Halton>,
+}
+
+#[cfg(feature = "rand")]
+impl HaltonDistribution {
+    /// Creates a new [`HaltonDistribution`] wrapping a fresh [`Halton`]
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - An array of two integers used as bases for generating the sequence
+    /// * `scale` - An array of two integers used as scales for each dimension
+    pub fn new(base: [u32; 2], scale: [u32; 2]) -> Self {
+        Self {
+            inner: core::cell::RefCell::new(Halton::new(base, scale)),
+        }
+    }
+
+    /// Resets the state of the underlying sequence generator to a specific seed value
+    pub fn reseed(&self, seed: u32) {
+        self.inner.borrow_mut().reseed(seed);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<[f64; 2]> for HaltonDistribution {
+    fn sample<R: rand::Rng + ?Sized>(&self, _rng: &mut R) -> [f64; 2] {
+        let mut hgen = self.inner.borrow_mut();
+        let raw = hgen.pop();
+        [
+            raw[0] as f64 / hgen.vdc0.factor as f64,
+            raw[1] as f64 / hgen.vdc1.factor as f64,
+        ]
+    }
 }
 
 macro_rules! div_mod_3_iter {
@@ -159,7 +713,7 @@ pub fn div_mod_3_u8(n: u8) -> (u8, u8) {
 /// # Examples
 ///
 /// ```rust
-/// use lds_gen::ilds::div_mod_3_u8;
+/// use lds_rs::ilds::div_mod_3_u8;
 ///
 /// let (q, r) = div_mod_3_u8(10);
 /// assert_eq!(q, 3);
@@ -195,7 +749,7 @@ pub fn div_mod_3_u16(n: u16) -> (u16, u16) {
 /// # Examples
 ///
 /// ```rust
-/// use lds_gen::ilds::div_mod_3_u16;
+/// use lds_rs::ilds::div_mod_3_u16;
 ///
 /// let (q, r) = div_mod_3_u16(10000);
 /// assert_eq!(q, 3333);
@@ -234,7 +788,7 @@ pub fn div_mod_7_u8(n: u8) -> (u8, u8) {
 /// # Examples
 ///
 /// ```rust
-/// use lds_gen::ilds::div_mod_7_u8;
+/// use lds_rs::ilds::div_mod_7_u8;
 ///
 /// let (q, r) = div_mod_7_u8(10);
 /// assert_eq!(q, 1);
@@ -264,6 +818,120 @@ pub fn div_mod_7_u16(n: u16) -> (u16, u16) {
     }
 }
 
+/// Computes `(n / (2^K - 1), n % (2^K - 1))` using only shifts and adds.
+///
+/// Generalizes the `div_mod_3`/`div_mod_7` chunking trick above (division by a
+/// Mersenne number `2^K - 1` via repeated K-bit chunk summation) to an arbitrary `K`:
+/// `n`'s radix-`2^K` digits are folded into a running sum until the result fits in `K`
+/// bits, with the same `all-ones == 2^K - 1` carry fixup `div_mod_3`/`div_mod_7` apply.
+/// This avoids a hardware divider, which matters on FPGA/embedded targets with no
+/// divide instruction.
+///
+/// # Examples
+///
+/// ```rust
+/// use lds_rs::ilds::div_mod_mersenne;
+///
+/// let (q, r) = div_mod_mersenne::<2>(10); // base 3
+/// assert_eq!(q, 3);
+/// assert_eq!(r, 1);
+///
+/// let (q, r) = div_mod_mersenne::<3>(14); // base 7
+/// assert_eq!(q, 2);
+/// assert_eq!(r, 0);
+/// ```
+pub fn div_mod_mersenne<const K: u32>(n: u32) -> (u32, u32) {
+    let mask = (1u32 << K) - 1;
+    let mut quotient = 0;
+    let mut remainder = n;
+
+    while remainder > mask {
+        let q = remainder >> K;
+        let r = remainder & mask;
+        quotient += q;
+        remainder = q + r;
+    }
+
+    if remainder == mask {
+        (quotient + 1, 0)
+    } else {
+        (quotient, remainder)
+    }
+}
+
+/// Integer Van der Corput sequence generator for Mersenne bases (`2^K - 1`)
+///
+/// Generates the same sequence as [`VdCorput`] with `base = 2^K - 1`, but replaces
+/// every division by `base` with the shift-add [`div_mod_mersenne`], so generating a
+/// point involves no division at all — useful on FPGA/embedded targets with no
+/// hardware divider.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::ilds::VdCorputMersenne;
+/// let mut vdc = VdCorputMersenne::<2>::new(3); // base 3, scale 3
+/// vdc.reseed(0);
+/// assert_eq!(vdc.pop(), 9); // 1/3 * 27
+/// ```
+pub struct VdCorputMersenne<const K: u32> {
+    scale: u32,
+    count: u32,
+}
+
+impl<const K: u32> VdCorputMersenne<K> {
+    /// The base of this generator, `2^K - 1`.
+    pub const BASE: u32 = (1 << K) - 1;
+
+    /// Creates a new Mersenne-base integer Van der Corput sequence generator
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale factor determining the number of digits that can be represented
+    pub fn new(scale: u32) -> Self {
+        Self { scale, count: 0 }
+    }
+
+    /// Generates the next integer value in the sequence
+    pub fn pop(&mut self) -> u32 {
+        self.count += 1;
+        self.pop_at(self.count)
+    }
+
+    /// Computes the value the sequence would produce for count `n`, without mutating
+    /// the generator; `pop()` calls this with the advanced count.
+    pub fn pop_at(&self, n: u32) -> u32 {
+        let mut k = n;
+        let mut digits = [0u32; 32];
+        let mut len = 0usize;
+
+        while k != 0 {
+            let (q, r) = div_mod_mersenne::<K>(k);
+            digits[len] = r;
+            len += 1;
+            k = q;
+        }
+
+        let mut vdc = 0u32;
+        for &d in &digits[..len] {
+            vdc = vdc * Self::BASE + d;
+        }
+        for _ in 0..(self.scale as usize).saturating_sub(len) {
+            vdc *= Self::BASE;
+        }
+        vdc
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed value that determines the starting point of the sequence generation
+    pub fn reseed(&mut self, seed: u32) {
+        self.count = seed;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -351,4 +1019,374 @@ mod tests {
         assert_eq!(q, 2000);
         assert_eq!(r, 0);
     }
+
+    #[test]
+    fn test_vdcorput_iterator_take() {
+        let mut vdc = VdCorput::new(2, 10);
+        vdc.reseed(0);
+        let collected: Vec<u32> = vdc.take(4).collect();
+        assert_eq!(collected, vec![512, 256, 768, 128]);
+    }
+
+    #[test]
+    fn test_halton_iterator_take() {
+        let mut hgen = Halton::new([2, 3], [11, 7]);
+        hgen.reseed(0);
+        let collected: Vec<[u32; 2]> = hgen.take(2).collect();
+        assert_eq!(collected, vec![[1024, 729], [512, 1458]]);
+    }
+
+    #[test]
+    fn test_vdcorput_position_and_set_position() {
+        let mut vdc = VdCorput::new(2, 10);
+        vdc.reseed(5);
+        vdc.pop();
+        vdc.pop();
+        assert_eq!(vdc.position(), 7);
+
+        let mut resumed = VdCorput::new(2, 10);
+        resumed.set_position(vdc.position());
+        assert_eq!(vdc.pop(), resumed.pop());
+    }
+
+    #[test]
+    fn test_halton_position_and_set_position() {
+        let mut hgen = Halton::new([2, 3], [11, 7]);
+        hgen.reseed(5);
+        hgen.pop();
+        assert_eq!(hgen.position(), 6);
+
+        let mut resumed = Halton::new([2, 3], [11, 7]);
+        resumed.set_position(hgen.position());
+        assert_eq!(hgen.pop(), resumed.pop());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vdcorput_serde_round_trip_resumes_mid_stream() {
+        let mut vgen = VdCorput::new(2, 10);
+        for _ in 0..7 {
+            vgen.pop();
+        }
+
+        let snapshot = serde_json::to_string(&vgen).unwrap();
+        let mut resumed: VdCorput = serde_json::from_str(&snapshot).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(vgen.pop(), resumed.pop());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_halton_serde_round_trip_resumes_mid_stream() {
+        let mut hgen = Halton::new([2, 3], [11, 7]);
+        for _ in 0..7 {
+            hgen.pop();
+        }
+
+        let snapshot = serde_json::to_string(&hgen).unwrap();
+        let mut resumed: Halton = serde_json::from_str(&snapshot).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(hgen.pop(), resumed.pop());
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_vdcorput_distribution_sample() {
+        use rand::distributions::Distribution;
+
+        let dist = VdCorputDistribution::new(2, 10);
+        dist.reseed(0);
+        let mut rng = rand::thread_rng();
+
+        let first: f64 = dist.sample(&mut rng);
+        assert_eq!(first, 0.5);
+
+        let second: f64 = dist.sample(&mut rng);
+        assert_eq!(second, 0.25);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_halton_distribution_sample() {
+        use rand::distributions::Distribution;
+
+        let dist = HaltonDistribution::new([2, 3], [11, 7]);
+        dist.reseed(0);
+        let mut rng = rand::thread_rng();
+
+        let result: [f64; 2] = dist.sample(&mut rng);
+        assert_eq!(result[0], 0.5);
+        assert_eq!(result[1], 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_vdcorput_as_f64_compensated_matches_naive_divide() {
+        let mut vdc = VdCorput::new(2, 10);
+        vdc.reseed(0);
+        let popped = vdc.pop();
+        let naive = popped as f64 / 1024.0;
+        assert!((vdc.as_f64_compensated() - naive).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_halton_as_f64_compensated() {
+        let mut hgen = Halton::new([2, 3], [11, 7]);
+        hgen.reseed(0);
+        let popped = hgen.pop();
+        let naive = [popped[0] as f64 / 2048.0, popped[1] as f64 / 2187.0];
+        let compensated = hgen.as_f64_compensated();
+        assert!((compensated[0] - naive[0]).abs() < 1e-12);
+        assert!((compensated[1] - naive[1]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_stays_in_range() {
+        let mut vdc = ScrambledVdCorput::new(2, 10, 42);
+        for _ in 0..50 {
+            assert!(vdc.pop() < 1024);
+        }
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_differs_from_plain() {
+        let mut plain = VdCorput::new(2, 10);
+        let mut scrambled = ScrambledVdCorput::new(2, 10, 42);
+
+        let plain_seq: Vec<u32> = (0..10).map(|_| plain.pop()).collect();
+        let scrambled_seq: Vec<u32> = (0..10).map(|_| scrambled.pop()).collect();
+
+        assert_ne!(plain_seq, scrambled_seq);
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_flat_mode() {
+        let mut vdc = ScrambledVdCorput::with_options(2, 10, 42, IntScrambleMode::Flat, 1);
+        for _ in 0..20 {
+            assert!(vdc.pop() < 1024);
+        }
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_leap() {
+        let mut leaping = ScrambledVdCorput::with_options(2, 10, 42, IntScrambleMode::Owen, 3);
+        let mut stepping = ScrambledVdCorput::new(2, 10, 42);
+
+        let leaped = leaping.pop();
+        stepping.pop();
+        stepping.pop();
+        let stepped = stepping.pop();
+
+        assert_eq!(leaped, stepped);
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_reseed() {
+        let mut vdc = ScrambledVdCorput::new(2, 10, 42);
+        vdc.reseed(7);
+        let first = vdc.pop();
+        vdc.reseed(7);
+        let second = vdc.pop();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_pop_at_matches_pop() {
+        let mut vdc = ScrambledVdCorput::new(3, 5, 7);
+        for n in 1..=10u32 {
+            assert_eq!(vdc.pop(), vdc.pop_at(n));
+        }
+    }
+
+    #[test]
+    fn test_scrambled_halton_pop() {
+        let mut hgen = ScrambledHalton::new([2, 3], [11, 7], 42);
+        hgen.reseed(0);
+        let res = hgen.pop();
+        assert!(res[0] < 2048);
+        assert!(res[1] < 2187);
+    }
+
+    #[test]
+    fn test_scrambled_halton_iterator_take() {
+        let mut hgen = ScrambledHalton::new([2, 3], [11, 7], 42);
+        hgen.reseed(0);
+        let collected: Vec<[u32; 2]> = hgen.take(3).collect();
+        assert_eq!(collected.len(), 3);
+    }
+
+    #[test]
+    fn test_div_mod_mersenne_matches_div_mod_3() {
+        for n in 0u32..=255 {
+            let (q3, r3) = div_mod_3_u8(n as u8);
+            let (q, r) = div_mod_mersenne::<2>(n);
+            assert_eq!(q, q3 as u32);
+            assert_eq!(r, r3 as u32);
+        }
+    }
+
+    #[test]
+    fn test_div_mod_mersenne_matches_div_mod_7() {
+        for n in 0u32..=255 {
+            let (q7, r7) = div_mod_7_u8(n as u8);
+            let (q, r) = div_mod_mersenne::<3>(n);
+            assert_eq!(q, q7 as u32);
+            assert_eq!(r, r7 as u32);
+        }
+    }
+
+    #[test]
+    fn test_vdcorput_mersenne_pop() {
+        let mut vdc = VdCorputMersenne::<2>::new(3);
+        vdc.reseed(0);
+        assert_eq!(vdc.pop(), 9); // 1/3 * 27
+        assert_eq!(vdc.pop(), 18); // 2/3 * 27
+        assert_eq!(vdc.pop(), 3); // 1/9 * 27
+    }
+
+    #[test]
+    fn test_vdcorput_mersenne_reseed() {
+        let mut vdc = VdCorputMersenne::<2>::new(3);
+        vdc.reseed(5);
+        let first = vdc.pop();
+        vdc.reseed(5);
+        let second = vdc.pop();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_vdcorput_mersenne_matches_pop_at() {
+        let mut vdc = VdCorputMersenne::<3>::new(5);
+        vdc.reseed(0);
+        for n in 1..=20u32 {
+            assert_eq!(vdc.pop(), vdc.pop_at(n));
+        }
+    }
+
+    #[test]
+    fn test_vdcorput64_matches_vdcorput() {
+        let mut vdc32 = VdCorput::new(2, 10);
+        let mut vdc64 = VdCorput64::new(2, 10);
+        vdc32.reseed(10);
+        vdc64.reseed(10);
+        for _ in 0..20 {
+            assert_eq!(vdc32.pop() as u64, vdc64.pop());
+        }
+    }
+
+    #[test]
+    fn test_vdcorput64_deep_scale() {
+        let mut vdc = VdCorput64::new(2, 40);
+        vdc.reseed(0);
+        assert_eq!(vdc.pop(), 1u64 << 39); // 0.5 * 2^40
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64")]
+    fn test_vdcorput64_overflow_panics() {
+        VdCorput64::new(10, 25); // 10^25 > u64::MAX
+    }
+
+    #[test]
+    fn test_vdcorput64_reseed() {
+        let mut vdc = VdCorput64::new(3, 15);
+        vdc.reseed(7);
+        let first = vdc.pop();
+        vdc.reseed(7);
+        let second = vdc.pop();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_vdcorput64_position_and_set_position() {
+        let mut vdc = VdCorput64::new(2, 20);
+        for _ in 0..5 {
+            vdc.pop();
+        }
+        let pos = vdc.position();
+        let expected = vdc.pop();
+
+        let mut resumed = VdCorput64::new(2, 20);
+        resumed.set_position(pos);
+        assert_eq!(resumed.pop(), expected);
+    }
+
+    #[test]
+    fn test_vdcorput64_iterator_take() {
+        let mut vdc = VdCorput64::new(2, 10);
+        let vals: Vec<u64> = vdc.by_ref().take(3).collect();
+        assert_eq!(vals.len(), 3);
+    }
+
+    #[test]
+    fn test_halton64_matches_halton() {
+        let mut h32 = Halton::new([2, 3], [10, 10]);
+        let mut h64 = Halton64::new([2, 3], [10, 10]);
+        h32.reseed(10);
+        h64.reseed(10);
+        for _ in 0..20 {
+            let a = h32.pop();
+            let b = h64.pop();
+            assert_eq!(a[0] as u64, b[0]);
+            assert_eq!(a[1] as u64, b[1]);
+        }
+    }
+
+    #[test]
+    fn test_halton64_reseed() {
+        let mut h = Halton64::new([2, 3], [20, 15]);
+        h.reseed(8);
+        let first = h.pop();
+        h.reseed(8);
+        let second = h.pop();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_halton64_position_and_set_position() {
+        let mut h = Halton64::new([2, 3], [20, 15]);
+        for _ in 0..5 {
+            h.pop();
+        }
+        let pos = h.position();
+        let expected = h.pop();
+
+        let mut resumed = Halton64::new([2, 3], [20, 15]);
+        resumed.set_position(pos);
+        assert_eq!(resumed.pop(), expected);
+    }
+
+    #[test]
+    fn test_halton64_iterator_take() {
+        let mut h = Halton64::new([2, 3], [10, 10]);
+        let vals: Vec<[u64; 2]> = h.by_ref().take(3).collect();
+        assert_eq!(vals.len(), 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vdcorput64_serde_round_trip_resumes_mid_stream() {
+        let mut vdc = VdCorput64::new(2, 20);
+        for _ in 0..5 {
+            vdc.pop();
+        }
+        let json = serde_json::to_string(&vdc).unwrap();
+        let mut restored: VdCorput64 = serde_json::from_str(&json).unwrap();
+        assert_eq!(vdc.pop(), restored.pop());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_halton64_serde_round_trip_resumes_mid_stream() {
+        let mut h = Halton64::new([2, 3], [20, 15]);
+        for _ in 0..5 {
+            h.pop();
+        }
+        let json = serde_json::to_string(&h).unwrap();
+        let mut restored: Halton64 = serde_json::from_str(&json).unwrap();
+        assert_eq!(h.pop(), restored.pop());
+    }
 }
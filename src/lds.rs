@@ -1,6 +1,14 @@
 // #![feature(unboxed_closures)]
 
-const TWO_PI: f64 = std::f64::consts::TAU;
+use crate::mathops::{cos, floor, sin, sqrt, TAU};
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+const TWO_PI: f64 = TAU;
 
 /// Van der Corput sequence
 ///
@@ -33,9 +41,9 @@ pub fn vdc(k: usize, base: usize) -> f64 {
 /// Properties:
 ///
 /// * `count`: The `count` property is used to keep track of the current iteration count of the Van der
-/// Corput sequence. It starts at 0 and increments by 1 each time the `pop()` method is called.
+///   Corput sequence. It starts at 0 and increments by 1 each time the `pop()` method is called.
 /// * `base`: The `base` property represents the base of the Van der Corput sequence. It determines the
-/// number of digits used in each element of the sequence.
+///   number of digits used in each element of the sequence.
 ///
 /// # Examples
 ///
@@ -48,6 +56,7 @@ pub fn vdc(k: usize, base: usize) -> f64 {
 ///
 /// assert_eq!(result, 0.8125);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VdCorput {
     count: usize,
     base: usize,
@@ -60,8 +69,8 @@ impl VdCorput {
     /// Arguments:
     ///
     /// * `base`: The `base` parameter is an integer value that is used to generate the Van der Corput
-    /// sequence. It determines the base of the sequence, which affects the distribution and pattern of the
-    /// generated numbers.
+    ///   sequence. It determines the base of the sequence, which affects the distribution and pattern of the
+    ///   generated numbers.
     ///
     /// Returns:
     ///
@@ -78,7 +87,28 @@ impl VdCorput {
     /// The `pop` function returns a `f64` value, which is the next value in the Van der Corput sequence.
     pub fn pop(&mut self) -> f64 {
         self.count += 1;
-        vdc(self.count, self.base)
+        // Disambiguated: `impl Iterator for VdCorput` below also brings `Iterator::nth`
+        // into scope, and a plain `self.nth(...)` call resolves to that instead of the
+        // inherent radical-inverse `nth` below.
+        Self::nth(self, self.count)
+    }
+
+    /// Computes the radical inverse of index `n` directly, without mutating the generator.
+    ///
+    /// This is the same value `pop()` would have produced on its `n`-th call (counting from a
+    /// freshly-reseeded-to-zero generator), so it gives random access into the sequence and lets
+    /// callers fan points out across threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lds_rs::VdCorput;
+    ///
+    /// let vgen = VdCorput::new(2);
+    /// assert_eq!(vgen.nth(11), 0.8125);
+    /// ```
+    pub fn nth(&self, n: usize) -> f64 {
+        vdc(n, self.base)
     }
 
     /// The below code is a Rust function called `reseed` that is used to reset the state of a sequence
@@ -88,6 +118,46 @@ impl VdCorput {
     pub fn reseed(&mut self, seed: usize) {
         self.count = seed;
     }
+
+    /// Returns the current position (the last seed/count) of this [`VdCorput`].
+    ///
+    /// This is mainly useful for checkpointing a generator built on top of
+    /// [`VdCorput`] (e.g. for serialization or sharding), since every nested
+    /// `VdCorput` inside a composite generator advances its `count` in lockstep.
+    #[allow(dead_code)]
+    pub(crate) const fn position(&self) -> usize {
+        self.count
+    }
+
+    /// Creates a new [`VdCorput`] with the given `base`, already seeded to `seed`.
+    ///
+    /// Equivalent to calling [`VdCorput::new`] followed by [`VdCorput::reseed`], but lets an
+    /// iterator be started at any offset without a separate `reseed` call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lds_rs::VdCorput;
+    ///
+    /// let mut vgen = VdCorput::seeded(2, 10);
+    /// assert_eq!(vgen.pop(), 0.8125);
+    /// ```
+    pub fn seeded(base: usize, seed: usize) -> Self {
+        let mut vgen = Self::new(base);
+        vgen.reseed(seed);
+        vgen
+    }
+}
+
+/// Allows a [`VdCorput`] to be used as a standard Rust iterator (e.g.
+/// `VdCorput::new(2).skip(10).take(100).collect()`). The sequence is infinite, so
+/// `next()` always returns `Some`.
+impl Iterator for VdCorput {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        Some(self.pop())
+    }
 }
 
 /// The [`Halton`] struct is a sequence generator that generates points in a 2-dimensional space using the
@@ -96,11 +166,11 @@ impl VdCorput {
 /// Properties:
 ///
 /// * `vdc0`: A variable of type [`VdCorput`] that represents the Van der Corput sequence generator for
-/// the first base. The Van der Corput sequence is a low-discrepancy sequence that is commonly used in
-/// quasi-Monte Carlo methods. It generates a sequence of numbers between 0 and
+///   the first base. The Van der Corput sequence is a low-discrepancy sequence that is commonly used in
+///   quasi-Monte Carlo methods. It generates a sequence of numbers between 0 and
 /// * `vdc1`: The `vdc1` property is an instance of the [`VdCorput`] struct, which is responsible for
-/// generating the Van der Corput sequence with a base of 3. The Van der Corput sequence is another
-/// low-discrepancy sequence commonly used in quasi-Monte Carlo methods
+///   generating the Van der Corput sequence with a base of 3. The Van der Corput sequence is another
+///   low-discrepancy sequence commonly used in quasi-Monte Carlo methods
 ///
 /// # Examples
 ///
@@ -112,6 +182,7 @@ impl VdCorput {
 /// let result = hgen.pop();
 /// assert_eq!(result[0], 0.8125);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Halton {
     vdc0: VdCorput,
     vdc1: VdCorput,
@@ -124,8 +195,8 @@ impl Halton {
     /// Arguments:
     ///
     /// * `base`: The `base` parameter is an array of two `usize` values. These values are used as the bases
-    /// for generating the Halton sequence. The first value in the array (`base[0]`) is used as the base for
-    /// generating the first component of the Halton sequence, and the second
+    ///   for generating the Halton sequence. The first value in the array (`base[0]`) is used as the base for
+    ///   generating the first component of the Halton sequence, and the second
     ///
     /// Returns:
     ///
@@ -166,6 +237,23 @@ impl Halton {
         self.vdc0.reseed(seed);
         self.vdc1.reseed(seed);
     }
+
+    /// Creates a new [`Halton`] with the given bases, already seeded to `seed`.
+    pub fn seeded(base0: usize, base1: usize, seed: usize) -> Self {
+        let mut hgen = Self::new(base0, base1);
+        hgen.reseed(seed);
+        hgen
+    }
+}
+
+/// Allows a [`Halton`] to be used as a standard Rust iterator. The sequence is infinite,
+/// so `next()` always returns `Some`.
+impl Iterator for Halton {
+    type Item = [f64; 2];
+
+    fn next(&mut self) -> Option<[f64; 2]> {
+        Some(self.pop())
+    }
 }
 
 /// Circle sequence generator
@@ -186,8 +274,36 @@ impl Halton {
 /// let result = cgen.pop();
 /// assert_eq!(result[0], 1.0);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Circle {
     vdc: VdCorput,
+    table: Option<Vec<[f64; 2]>>,
+}
+
+/// Builds a table of `table_size` uniformly spaced `[sin theta, cos theta]` entries
+/// spanning `[0, 2*pi)`, used by the `with_table` constructors below to trade a small,
+/// tunable amount of discrepancy for replacing a `sin`/`cos` call with a table lookup.
+fn build_sincos_table(table_size: usize) -> Vec<[f64; 2]> {
+    (0..table_size)
+        .map(|i| {
+            let theta = TWO_PI * i as f64 / table_size as f64;
+            [sin(theta), cos(theta)]
+        })
+        .collect()
+}
+
+/// Maps `theta` (in `[0, 2*pi)`) to a `[sin theta, cos theta]` pair via linear
+/// interpolation between the two nearest entries of a table built by
+/// [`build_sincos_table`].
+fn lookup_sincos(theta: f64, table: &[[f64; 2]]) -> [f64; 2] {
+    let n = table.len();
+    let frac = theta / TWO_PI * n as f64;
+    let idx = floor(frac) as usize % n;
+    let next = (idx + 1) % n;
+    let t = frac - floor(frac);
+    let [s0, c0] = table[idx];
+    let [s1, c1] = table[next];
+    [s0 + t * (s1 - s0), c0 + t * (c1 - c0)]
 }
 
 impl Circle {
@@ -198,8 +314,8 @@ impl Circle {
     /// Arguments:
     ///
     /// * `base`: The `base` parameter in the `new` function is the base value used to generate the Van
-    /// der Corput sequence. The Van der Corput sequence is a low-discrepancy sequence used in
-    /// quasi-Monte Carlo methods. It is generated by reversing the digits of the fractional part of the
+    ///   der Corput sequence. The Van der Corput sequence is a low-discrepancy sequence used in
+    ///   quasi-Monte Carlo methods. It is generated by reversing the digits of the fractional part of the
     ///
     /// Returns:
     ///
@@ -207,6 +323,20 @@ impl Circle {
     pub fn new(base: usize) -> Self {
         Circle {
             vdc: VdCorput::new(base),
+            table: None,
+        }
+    }
+
+    /// Creates a new [`Circle`] that looks up `sin`/`cos` from a precomputed table of
+    /// `table_size` entries instead of calling the trig functions directly.
+    ///
+    /// This trades a small, tunable amount of accuracy (via linear interpolation between
+    /// adjacent table entries) for speed in trig-heavy sampling loops; larger `table_size`
+    /// reduces the interpolation error at the cost of more memory.
+    pub fn with_table(base: usize, table_size: usize) -> Self {
+        Circle {
+            vdc: VdCorput::new(base),
+            table: Some(build_sincos_table(table_size)),
         }
     }
 
@@ -221,7 +351,10 @@ impl Circle {
     pub fn pop(&mut self) -> [f64; 2] {
         // let two_pi = 2.0/// (-1.0 as f64).acos(); // ???
         let theta = self.vdc.pop() * TWO_PI; // map to [0, 2*pi];
-        [theta.sin(), theta.cos()]
+        match &self.table {
+            Some(table) => lookup_sincos(theta, table),
+            None => [sin(theta), cos(theta)],
+        }
     }
 
     /// The below code is a Rust function called `reseed` that is used to reset the state of a sequence
@@ -232,6 +365,23 @@ impl Circle {
     pub fn reseed(&mut self, seed: usize) {
         self.vdc.reseed(seed);
     }
+
+    /// Creates a new [`Circle`] with the given `base`, already seeded to `seed`.
+    pub fn seeded(base: usize, seed: usize) -> Self {
+        let mut cgen = Self::new(base);
+        cgen.reseed(seed);
+        cgen
+    }
+}
+
+/// Allows a [`Circle`] to be used as a standard Rust iterator. The sequence is infinite,
+/// so `next()` always returns `Some`.
+impl Iterator for Circle {
+    type Item = [f64; 2];
+
+    fn next(&mut self) -> Option<[f64; 2]> {
+        Some(self.pop())
+    }
 }
 
 /// Sphere sequence generator
@@ -241,10 +391,10 @@ impl Circle {
 /// Properties:
 ///
 /// * `vdc`: The `vdc` property is an instance of the [`VdCorput`] struct. It is used to generate a Van
-/// der Corput sequence, which is a low-discrepancy sequence used for sampling points in a unit
-/// interval.
+///   der Corput sequence, which is a low-discrepancy sequence used for sampling points in a unit
+///   interval.
 /// * `cirgen`: The `cirgen` property is an instance of the [`Circle`] struct. It is responsible for
-/// generating points on a circle.
+///   generating points on a circle.
 ///
 /// # Examples
 ///
@@ -256,6 +406,7 @@ impl Circle {
 /// let result = sgen.pop();
 /// assert_eq!(result[2], -0.5);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sphere {
     vdc: VdCorput,
     cirgen: Circle,
@@ -269,8 +420,8 @@ impl Sphere {
     /// Arguments:
     ///
     /// * `base`: The `base` parameter is an array of `usize` values. It is used to initialize the `Sphere`
-    /// struct. The first element of the `base` array is used to create a new `VdCorput` struct, and the
-    /// second element is used to create a new `Circle
+    ///   struct. The first element of the `base` array is used to create a new `VdCorput` struct, and the
+    ///   second element is used to create a new `Circle
     ///
     /// Returns:
     ///
@@ -282,6 +433,17 @@ impl Sphere {
         }
     }
 
+    /// Creates a new [`Sphere`] whose longitude coordinate (generated by its inner
+    /// [`Circle`]) looks up `sin`/`cos` from a precomputed table of `table_size` entries
+    /// instead of calling the trig functions directly. See [`Circle::with_table`] for the
+    /// discrepancy-vs-speed tradeoff of the chosen `table_size`.
+    pub fn with_table(base: &[usize], table_size: usize) -> Self {
+        Sphere {
+            vdc: VdCorput::new(base[0]),
+            cirgen: Circle::with_table(base[1], table_size),
+        }
+    }
+
     /// Returns the pop of this [`Sphere`].
     ///
     /// The `pop` function returns a random point on a sphere using the VDC and cirgen generators.
@@ -293,7 +455,7 @@ impl Sphere {
     /// value (`cosphi`) represents the z coordinate.
     pub fn pop(&mut self) -> [f64; 3] {
         let cosphi = 2.0 * self.vdc.pop() - 1.0; // map to [-1, 1];
-        let sinphi = (1.0 - cosphi * cosphi).sqrt();
+        let sinphi = sqrt(1.0 - cosphi * cosphi);
         let [c, s] = self.cirgen.pop();
         [sinphi * c, sinphi * s, cosphi]
     }
@@ -307,6 +469,23 @@ impl Sphere {
         self.cirgen.reseed(seed);
         self.vdc.reseed(seed);
     }
+
+    /// Creates a new [`Sphere`] with the given `base`, already seeded to `seed`.
+    pub fn seeded(base: &[usize], seed: usize) -> Self {
+        let mut sgen = Self::new(base);
+        sgen.reseed(seed);
+        sgen
+    }
+}
+
+/// Allows a [`Sphere`] to be used as a standard Rust iterator. The sequence is infinite,
+/// so `next()` always returns `Some`.
+impl Iterator for Sphere {
+    type Item = [f64; 3];
+
+    fn next(&mut self) -> Option<[f64; 3]> {
+        Some(self.pop())
+    }
 }
 
 /// The `Sphere3Hopf` struct is a sequence generator for the S(3) sequence using Hopf coordinates.
@@ -314,13 +493,13 @@ impl Sphere {
 /// Properties:
 ///
 /// * `vdc0`: An instance of the VdCorput sequence generator used for the first coordinate of the Hopf
-/// coordinates.
+///   coordinates.
 /// * `vdc1`: The `vdc1` property is an instance of the [`VdCorput`] struct, which is used to generate a
-/// Van der Corput sequence. This sequence is a low-discrepancy sequence that is commonly used in
-/// numerical methods for generating random numbers. In this case, it is
+///   Van der Corput sequence. This sequence is a low-discrepancy sequence that is commonly used in
+///   numerical methods for generating random numbers. In this case, it is
 /// * `vdc2`: The `vdc2` property is an instance of the [`VdCorput`] struct, which is used to generate a
-/// Van der Corput sequence. This sequence is a low-discrepancy sequence that is commonly used in
-/// numerical methods for generating random numbers. In the context of the `
+///   Van der Corput sequence. This sequence is a low-discrepancy sequence that is commonly used in
+///   numerical methods for generating random numbers. In the context of the `
 ///
 /// The `Sphere3Hopf` class is a sequence generator that generates points on a
 /// 3-sphere using the Hopf fibration. It uses three instances of the `VdCorput`
@@ -342,10 +521,12 @@ impl Sphere {
 /// let result = sgen.pop();
 /// assert_approx_eq!(result[2], 0.4472135954999573);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sphere3Hopf {
     vdc0: VdCorput,
     vdc1: VdCorput,
     vdc2: VdCorput,
+    table: Option<Vec<[f64; 2]>>,
 }
 
 impl Sphere3Hopf {
@@ -357,8 +538,8 @@ impl Sphere3Hopf {
     /// Arguments:
     ///
     /// * `base`: The `base` parameter is an array of three `usize` values. These values are used to
-    /// initialize three instances of the `VdCorput` struct, which is a type of quasi-random number
-    /// generator. Each `VdCorput` instance is initialized with a different base value from the
+    ///   initialize three instances of the `VdCorput` struct, which is a type of quasi-random number
+    ///   generator. Each `VdCorput` instance is initialized with a different base value from the
     ///
     /// Returns:
     ///
@@ -368,6 +549,20 @@ impl Sphere3Hopf {
             vdc0: VdCorput::new(base[0]),
             vdc1: VdCorput::new(base[1]),
             vdc2: VdCorput::new(base[2]),
+            table: None,
+        }
+    }
+
+    /// Creates a new [`Sphere3Hopf`] that looks up `sin`/`cos` from a precomputed table of
+    /// `table_size` entries instead of calling the trig functions directly. See
+    /// [`Circle::with_table`] for the discrepancy-vs-speed tradeoff of the chosen
+    /// `table_size`.
+    pub fn with_table(base: &[usize], table_size: usize) -> Self {
+        Sphere3Hopf {
+            vdc0: VdCorput::new(base[0]),
+            vdc1: VdCorput::new(base[1]),
+            vdc2: VdCorput::new(base[2]),
+            table: Some(build_sincos_table(table_size)),
         }
     }
 
@@ -392,13 +587,23 @@ impl Sphere3Hopf {
         let phi = self.vdc0.pop() * TWO_PI; // map to [0, 2*pi];
         let psy = self.vdc1.pop() * TWO_PI; // map to [0, 2*pi];
         let vd = self.vdc2.pop();
-        let cos_eta = vd.sqrt();
-        let sin_eta = (1.0 - vd).sqrt();
+        let cos_eta = sqrt(vd);
+        let sin_eta = sqrt(1.0 - vd);
+
+        let (sin_psy, cos_psy, sin_sum, cos_sum) = match &self.table {
+            Some(table) => {
+                let [sp, cp] = lookup_sincos(psy, table);
+                let [ss, cs] = lookup_sincos(phi + psy, table);
+                (sp, cp, ss, cs)
+            }
+            None => (sin(psy), cos(psy), sin(phi + psy), cos(phi + psy)),
+        };
+
         [
-            cos_eta * psy.cos(),
-            cos_eta * psy.sin(),
-            sin_eta * (phi + psy).cos(),
-            sin_eta * (phi + psy).sin(),
+            cos_eta * cos_psy,
+            cos_eta * sin_psy,
+            sin_eta * cos_sum,
+            sin_eta * sin_sum,
         ]
     }
 
@@ -412,6 +617,400 @@ impl Sphere3Hopf {
         self.vdc1.reseed(seed);
         self.vdc2.reseed(seed);
     }
+
+    /// Creates a new [`Sphere3Hopf`] with the given `base`, already seeded to `seed`.
+    pub fn seeded(base: &[usize], seed: usize) -> Self {
+        let mut sgen = Self::new(base);
+        sgen.reseed(seed);
+        sgen
+    }
+}
+
+/// Allows a [`Sphere3Hopf`] to be used as a standard Rust iterator. The sequence is
+/// infinite, so `next()` always returns `Some`.
+impl Iterator for Sphere3Hopf {
+    type Item = [f64; 4];
+
+    fn next(&mut self) -> Option<[f64; 4]> {
+        Some(self.pop())
+    }
+}
+
+/// The [`HaltonN`] struct is a sequence generator that generates points in an n-dimensional space using
+/// the Halton sequence.
+///
+/// Properties:
+///
+/// * `vdcs`: A vector of [`VdCorput`] generators, one per dimension, each with its own base.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::HaltonN;
+///
+/// let mut hgen = HaltonN::new(&[2, 3, 5, 7, 11]);
+/// hgen.reseed(10);
+/// let result = hgen.pop_vec();
+/// assert_eq!(result[0], 0.8125);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HaltonN {
+    vdcs: Vec<VdCorput>,
+}
+
+impl HaltonN {
+    /// Creates a new [`HaltonN`].
+    ///
+    /// The `new` function creates a new `HaltonN` object with one [`VdCorput`] generator per entry in
+    /// `base`.
+    ///
+    /// Arguments:
+    ///
+    /// * `base`: A slice of `usize` values, one per dimension, used as the base for that dimension's
+    ///   [`VdCorput`] generator.
+    ///
+    /// Returns:
+    ///
+    /// The `new` function returns an instance of the `HaltonN` struct.
+    pub fn new(base: &[usize]) -> Self {
+        Self {
+            vdcs: base.iter().map(|&b| VdCorput::new(b)).collect(),
+        }
+    }
+
+    /// Creates a new [`HaltonN`] of dimension `dim`, drawing its bases automatically from
+    /// the first `dim` entries of [`PRIME_TABLE`] (as Halton originally suggested: use the
+    /// first `M` primes as bases for `M` dimensions).
+    ///
+    /// This spares the caller from hand-picking `dim` coprime bases, at the cost of losing
+    /// control over which bases are used; call [`HaltonN::new`] directly when specific bases
+    /// are required.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim`: The number of dimensions to sample. Must not exceed `PRIME_TABLE.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lds_rs::HaltonN;
+    ///
+    /// let mut hgen = HaltonN::with_dim(5);
+    /// hgen.reseed(10);
+    /// let result = hgen.pop_vec();
+    /// assert_eq!(result[0], 0.8125);
+    /// ```
+    pub fn with_dim(dim: usize) -> Self {
+        Self::new(&PRIME_TABLE[0..dim])
+    }
+
+    /// Returns the pop vec of this [`HaltonN`].
+    ///
+    /// The `pop_vec` function returns a vector containing the next value from each [`VdCorput`]
+    /// generator.
+    ///
+    /// Returns:
+    ///
+    /// The `pop_vec` function returns a `Vec<f64>`.
+    pub fn pop_vec(&mut self) -> Vec<f64> {
+        self.vdcs.iter_mut().map(|vdc| vdc.pop()).collect()
+    }
+
+    /// The below code is a Rust function called `reseed` that is used to reset the state of a sequence
+    /// generator to a specific seed value. This allows the sequence generator to start generating the
+    /// sequence from the beginning or from a specific point in the sequence, depending on the value of the
+    /// seed.
+    pub fn reseed(&mut self, seed: usize) {
+        for vdc in &mut self.vdcs {
+            vdc.reseed(seed);
+        }
+    }
+}
+
+/// Hammersley point set generator
+///
+/// Unlike [`HaltonN`], the [`Hammersley`] set assumes the total number of points `n` is
+/// known up front. The `i`-th point (`i = 1..=n`) uses `i / n` as its first coordinate and
+/// the Van der Corput sequence in the remaining coordinates, which gives strictly better
+/// discrepancy than Halton in that `i / n` coordinate.
+///
+/// Properties:
+///
+/// * `vdcs`: A vector of [`VdCorput`] generators, one per remaining dimension.
+/// * `n`: The total number of points in the set.
+/// * `count`: The index of the most recently generated point (`0` before the first `pop`).
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::lds::Hammersley;
+///
+/// let mut hgen = Hammersley::new(&[2, 3], 10);
+/// hgen.reseed(0);
+/// let result = hgen.pop();
+/// assert_eq!(result[0], 0.1);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hammersley {
+    vdcs: Vec<VdCorput>,
+    n: usize,
+    count: usize,
+}
+
+impl Hammersley {
+    /// Creates a new [`Hammersley`] generator.
+    ///
+    /// Arguments:
+    ///
+    /// * `base`: A slice of `usize` values, one per remaining dimension (i.e. excluding the
+    ///   `i / n` coordinate), used as the base for that dimension's [`VdCorput`] generator.
+    /// * `n`: The total number of points the set will contain.
+    pub fn new(base: &[usize], n: usize) -> Self {
+        Self {
+            vdcs: base.iter().map(|&b| VdCorput::new(b)).collect(),
+            n,
+            count: 0,
+        }
+    }
+
+    /// Generates the next point in the Hammersley set.
+    ///
+    /// Returns a `Vec<f64>` of length `base.len() + 1` whose first entry is
+    /// `count / n` and whose remaining entries come from the `VdCorput` generators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `n` points have been requested.
+    pub fn pop(&mut self) -> Vec<f64> {
+        self.count += 1;
+        assert!(
+            self.count <= self.n,
+            "Hammersley: requested more points ({}) than the set size (n = {})",
+            self.count,
+            self.n
+        );
+        let mut point = Vec::with_capacity(self.vdcs.len() + 1);
+        point.push(self.count as f64 / self.n as f64);
+        point.extend(self.vdcs.iter_mut().map(|vdc| vdc.pop()));
+        point
+    }
+
+    /// The below code is a Rust function called `reseed` that is used to reset the state of a sequence
+    /// generator to a specific seed value. This allows the sequence generator to start generating the
+    /// sequence from the beginning or from a specific point in the sequence, depending on the value of the
+    /// seed.
+    pub fn reseed(&mut self, seed: usize) {
+        self.count = seed;
+        for vdc in &mut self.vdcs {
+            vdc.reseed(seed);
+        }
+    }
+}
+
+/// A fixed-dimension, allocation-free variant of [`HaltonN`].
+///
+/// Built over `[VdCorput; N]` instead of `Vec<VdCorput>`, `pop()` returns a `[f64; N]`
+/// instead of a `Vec<f64>`, avoiding a heap allocation per point in tight sampling loops
+/// where the dimension is known at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::lds::HaltonConst;
+///
+/// let mut hgen = HaltonConst::new([2, 3]);
+/// hgen.reseed(10);
+/// let result = hgen.pop();
+/// assert_eq!(result[0], 0.8125);
+/// ```
+pub struct HaltonConst<const N: usize> {
+    vdcs: [VdCorput; N],
+}
+
+impl<const N: usize> HaltonConst<N> {
+    /// Creates a new [`HaltonConst`] with one [`VdCorput`] generator per entry in `base`.
+    pub fn new(base: [usize; N]) -> Self {
+        Self {
+            vdcs: base.map(VdCorput::new),
+        }
+    }
+
+    /// Generates the next point, one value popped from each dimension's generator.
+    pub fn pop(&mut self) -> [f64; N] {
+        core::array::from_fn(|i| self.vdcs[i].pop())
+    }
+
+    /// Resets the state of every dimension's generator to a specific seed value.
+    pub fn reseed(&mut self, seed: usize) {
+        for vdc in &mut self.vdcs {
+            vdc.reseed(seed);
+        }
+    }
+}
+
+use crate::splitmix::{mix64, permute_digit};
+
+/// The scrambling strategy used by a [`ScrambledVdCorput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrambleMode {
+    /// Nested (Owen) scrambling: the permutation applied to digit `i` depends on the
+    /// higher-order digits already seen, derived by hashing the digit prefix with the
+    /// generator's key.
+    Owen,
+    /// A cheaper Cranley-Patterson-style digital shift: one random offset per digit
+    /// position, added digit-wise modulo `base` (no carry).
+    DigitalShift,
+}
+
+/// A randomized, digitally-scrambled Van der Corput sequence generator.
+///
+/// The plain [`VdCorput`] sequence is deterministic and cannot provide error bars, and
+/// in high dimensions its raw digit structure correlates across axes. `ScrambledVdCorput`
+/// applies a per-digit scramble (seeded by `key`) so that `R` independent generators with
+/// different keys form independent randomized realizations, letting a caller estimate
+/// integration error from their variance while preserving the low-discrepancy structure.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::lds::{ScrambledVdCorput, ScrambleMode};
+///
+/// let mut vgen = ScrambledVdCorput::new(2, 42);
+/// vgen.reseed(10);
+/// let result = vgen.pop();
+/// assert!((0.0..1.0).contains(&result));
+///
+/// let mut sgen = ScrambledVdCorput::with_mode(2, 42, ScrambleMode::DigitalShift);
+/// sgen.reseed(10);
+/// let result = sgen.pop();
+/// assert!((0.0..1.0).contains(&result));
+/// ```
+pub struct ScrambledVdCorput {
+    base: usize,
+    count: usize,
+    key: u64,
+    mode: ScrambleMode,
+}
+
+impl ScrambledVdCorput {
+    /// Creates a new [`ScrambledVdCorput`] with Owen (nested) scrambling.
+    ///
+    /// Arguments:
+    ///
+    /// * `base`: The base of the sequence.
+    /// * `seed`: The RNG seed that determines this generator's scrambling key; different
+    ///   seeds give independent randomized realizations of the same base.
+    pub fn new(base: usize, seed: u64) -> Self {
+        Self::with_mode(base, seed, ScrambleMode::Owen)
+    }
+
+    /// Creates a new [`ScrambledVdCorput`] with an explicit [`ScrambleMode`].
+    pub fn with_mode(base: usize, seed: u64, mode: ScrambleMode) -> Self {
+        Self {
+            base,
+            count: 0,
+            key: mix64(seed),
+            mode,
+        }
+    }
+
+    /// Generates the next value in the scrambled sequence.
+    pub fn pop(&mut self) -> f64 {
+        self.count += 1;
+        self.nth(self.count)
+    }
+
+    /// Computes the scrambled radical inverse of index `n` directly, without mutating
+    /// the generator.
+    pub fn nth(&self, n: usize) -> f64 {
+        match self.mode {
+            ScrambleMode::Owen => self.nth_owen(n),
+            ScrambleMode::DigitalShift => self.nth_digital_shift(n),
+        }
+    }
+
+    fn nth_owen(&self, n: usize) -> f64 {
+        let mut digits = Vec::new();
+        let mut k = n;
+        while k != 0 {
+            digits.push(k % self.base);
+            k /= self.base;
+        }
+
+        let mut result = 0.0;
+        let mut scale = 1.0 / self.base as f64;
+        let mut prefix_key = self.key;
+        for &d in &digits {
+            let scrambled = permute_digit(d, self.base, prefix_key);
+            result += scrambled as f64 * scale;
+            scale /= self.base as f64;
+            prefix_key = mix64(prefix_key ^ (d as u64).wrapping_add(0x9E37_79B9_7F4A_7C15));
+        }
+        result
+    }
+
+    fn nth_digital_shift(&self, n: usize) -> f64 {
+        let mut result = 0.0;
+        let mut scale = 1.0 / self.base as f64;
+        let mut k = n;
+        let mut level = 0u64;
+        while k != 0 {
+            let d = k % self.base;
+            k /= self.base;
+            let shift = (mix64(self.key ^ level.wrapping_add(0xD1B5_4A32_D192_ED03)) as usize)
+                % self.base;
+            result += ((d + shift) % self.base) as f64 * scale;
+            scale /= self.base as f64;
+            level += 1;
+        }
+        result
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value.
+    pub fn reseed(&mut self, seed: usize) {
+        self.count = seed;
+    }
+}
+
+/// A randomized, digitally-scrambled [`HaltonN`], for running independent replicates of
+/// a multi-dimensional low-discrepancy sequence (see [`ScrambledVdCorput`]).
+pub struct ScrambledHaltonN {
+    vdcs: Vec<ScrambledVdCorput>,
+}
+
+impl ScrambledHaltonN {
+    /// Creates a new [`ScrambledHaltonN`] with Owen (nested) scrambling, one
+    /// [`ScrambledVdCorput`] per entry in `base`, each with an independent key derived
+    /// from `seed`.
+    pub fn new(base: &[usize], seed: u64) -> Self {
+        Self::with_mode(base, seed, ScrambleMode::Owen)
+    }
+
+    /// Creates a new [`ScrambledHaltonN`] with an explicit [`ScrambleMode`].
+    pub fn with_mode(base: &[usize], seed: u64, mode: ScrambleMode) -> Self {
+        Self {
+            vdcs: base
+                .iter()
+                .enumerate()
+                .map(|(i, &b)| {
+                    let key = mix64(seed ^ (i as u64).wrapping_add(0x2545_F491_4F6C_DD1D));
+                    ScrambledVdCorput::with_mode(b, key, mode)
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the next point, one value popped from each dimension's generator.
+    pub fn pop_vec(&mut self) -> Vec<f64> {
+        self.vdcs.iter_mut().map(|vdc| vdc.pop()).collect()
+    }
+
+    /// Resets the state of every dimension's generator to a specific seed value.
+    pub fn reseed(&mut self, seed: usize) {
+        for vdc in &mut self.vdcs {
+            vdc.reseed(seed);
+        }
+    }
 }
 
 // First 1000 prime numbers;
@@ -483,6 +1082,9 @@ pub const PRIME_TABLE: [usize; 1000] = [
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
     #[test]
     fn test_vdc() {
         assert_eq!(vdc(1, 2), 0.5);
@@ -491,4 +1093,322 @@ mod tests {
         assert_eq!(vdc(4, 2), 0.125);
         assert_eq!(vdc(5, 2), 0.625);
     }
+
+    #[test]
+    fn test_vdcorput_pop_advances_one_at_a_time() {
+        // Regression test: `impl Iterator for VdCorput` brings `Iterator::nth` into
+        // scope alongside the inherent radical-inverse `nth(&self, n) -> f64`, and a
+        // plain `self.nth(...)` call inside `pop` resolves to the former (which
+        // advances the iterator by `n` steps) instead of the latter. `pop` must call
+        // `Self::nth(self, self.count)` explicitly to stay on the radical-inverse path.
+        let mut vgen = VdCorput::new(2);
+        assert_eq!(vgen.pop(), vdc(1, 2));
+        assert_eq!(vgen.pop(), vdc(2, 2));
+        assert_eq!(vgen.pop(), vdc(3, 2));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vdcorput_serde_round_trip_resumes_mid_stream() {
+        let mut vgen = VdCorput::new(2);
+        for _ in 0..7 {
+            vgen.pop();
+        }
+
+        let snapshot = serde_json::to_string(&vgen).unwrap();
+        let mut resumed: VdCorput = serde_json::from_str(&snapshot).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(vgen.pop(), resumed.pop());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_haltonn_serde_round_trip_resumes_mid_stream() {
+        let mut hgen = HaltonN::new(&[2, 3, 5, 7, 11]);
+        for _ in 0..7 {
+            hgen.pop_vec();
+        }
+
+        let snapshot = serde_json::to_string(&hgen).unwrap();
+        let mut resumed: HaltonN = serde_json::from_str(&snapshot).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(hgen.pop_vec(), resumed.pop_vec());
+        }
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_stays_in_unit_interval() {
+        let mut vgen = ScrambledVdCorput::new(2, 42);
+        for _ in 0..50 {
+            let res = vgen.pop();
+            assert!((0.0..1.0).contains(&res));
+        }
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_differs_from_plain_vdcorput() {
+        let mut vgen = VdCorput::new(2);
+        let mut sgen = ScrambledVdCorput::new(2, 42);
+
+        let plain: Vec<f64> = (0..10).map(|_| vgen.pop()).collect();
+        let scrambled: Vec<f64> = (0..10).map(|_| sgen.pop()).collect();
+
+        assert_ne!(plain, scrambled);
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_different_keys_are_independent() {
+        let mut a = ScrambledVdCorput::new(2, 1);
+        let mut b = ScrambledVdCorput::new(2, 2);
+
+        let seq_a: Vec<f64> = (0..10).map(|_| a.pop()).collect();
+        let seq_b: Vec<f64> = (0..10).map(|_| b.pop()).collect();
+
+        assert_ne!(seq_a, seq_b);
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_nth_matches_pop() {
+        let mut vgen = ScrambledVdCorput::new(3, 7);
+        for _ in 0..9 {
+            vgen.pop();
+        }
+        let next_via_pop = vgen.pop();
+        let next_via_nth = vgen.nth(10);
+        assert_eq!(next_via_pop, next_via_nth);
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_digital_shift_mode() {
+        let mut vgen = ScrambledVdCorput::with_mode(2, 42, ScrambleMode::DigitalShift);
+        for _ in 0..50 {
+            let res = vgen.pop();
+            assert!((0.0..1.0).contains(&res));
+        }
+    }
+
+    #[test]
+    fn test_scrambled_vdcorput_reseed() {
+        let mut vgen = ScrambledVdCorput::new(2, 42);
+        vgen.reseed(10);
+        let first = vgen.pop();
+        vgen.reseed(10);
+        let second = vgen.pop();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_scrambled_haltonn_pop_vec() {
+        let mut hgen = ScrambledHaltonN::new(&[2, 3, 5], 42);
+        hgen.reseed(10);
+        for _ in 0..10 {
+            let res = hgen.pop_vec();
+            assert_eq!(res.len(), 3);
+            for v in res {
+                assert!((0.0..1.0).contains(&v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_haltonconst_matches_haltonn() {
+        let mut hgen = HaltonN::new(&[2, 3]);
+        let mut cgen = HaltonConst::new([2, 3]);
+        hgen.reseed(10);
+        cgen.reseed(10);
+
+        for _ in 0..10 {
+            assert_eq!(hgen.pop_vec(), cgen.pop().to_vec());
+        }
+    }
+
+    #[test]
+    fn test_haltonconst_reseed() {
+        let mut hgen = HaltonConst::new([2, 3, 5]);
+        hgen.reseed(10);
+        let first = hgen.pop();
+        hgen.reseed(10);
+        let second = hgen.pop();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_scrambled_haltonn_differs_from_plain_haltonn() {
+        let mut hgen = HaltonN::new(&[2, 3, 5]);
+        let mut sgen = ScrambledHaltonN::new(&[2, 3, 5], 42);
+
+        let plain: Vec<Vec<f64>> = (0..10).map(|_| hgen.pop_vec()).collect();
+        let scrambled: Vec<Vec<f64>> = (0..10).map(|_| sgen.pop_vec()).collect();
+
+        assert_ne!(plain, scrambled);
+    }
+
+    #[test]
+    fn test_hammersley_first_coordinate() {
+        let mut hgen = Hammersley::new(&[2, 3], 10);
+        hgen.reseed(0);
+        for i in 1..=10 {
+            let point = hgen.pop();
+            assert_eq!(point.len(), 3);
+            assert_eq!(point[0], i as f64 / 10.0);
+        }
+    }
+
+    #[test]
+    fn test_hammersley_remaining_coordinates_match_vdc() {
+        let mut hgen = Hammersley::new(&[2, 3], 5);
+        hgen.reseed(0);
+        for i in 1..=5 {
+            let point = hgen.pop();
+            assert_eq!(point[1], vdc(i, 2));
+            assert_eq!(point[2], vdc(i, 3));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "requested more points")]
+    fn test_hammersley_panics_past_n() {
+        let mut hgen = Hammersley::new(&[2], 3);
+        for _ in 0..4 {
+            hgen.pop();
+        }
+    }
+
+    #[test]
+    fn test_hammersley_reseed() {
+        let mut hgen = Hammersley::new(&[2, 3], 10);
+        hgen.reseed(5);
+        let first = hgen.pop();
+        hgen.reseed(5);
+        let second = hgen.pop();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_circle_with_table_close_to_exact_trig() {
+        let mut exact = Circle::new(2);
+        let mut tabled = Circle::with_table(2, 4096);
+        exact.reseed(10);
+        tabled.reseed(10);
+        for _ in 0..20 {
+            let a = exact.pop();
+            let b = tabled.pop();
+            assert!((a[0] - b[0]).abs() < 1e-3);
+            assert!((a[1] - b[1]).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_sphere_with_table_close_to_exact_trig() {
+        let mut exact = Sphere::new(&[2, 3]);
+        let mut tabled = Sphere::with_table(&[2, 3], 4096);
+        exact.reseed(10);
+        tabled.reseed(10);
+        for _ in 0..20 {
+            let a = exact.pop();
+            let b = tabled.pop();
+            for i in 0..3 {
+                assert!((a[i] - b[i]).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sphere3hopf_with_table_close_to_exact_trig() {
+        let mut exact = Sphere3Hopf::new(&[2, 3, 5]);
+        let mut tabled = Sphere3Hopf::with_table(&[2, 3, 5], 4096);
+        exact.reseed(10);
+        tabled.reseed(10);
+        for _ in 0..20 {
+            let a = exact.pop();
+            let b = tabled.pop();
+            for i in 0..4 {
+                assert!((a[i] - b[i]).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_haltonn_with_dim_matches_new_with_prime_table() {
+        let mut auto = HaltonN::with_dim(5);
+        let mut manual = HaltonN::new(&PRIME_TABLE[0..5]);
+        auto.reseed(10);
+        manual.reseed(10);
+        for _ in 0..10 {
+            assert_eq!(auto.pop_vec(), manual.pop_vec());
+        }
+    }
+
+    #[test]
+    fn test_vdcorput_seeded_matches_new_then_reseed() {
+        let mut seeded = VdCorput::seeded(2, 10);
+        let mut manual = VdCorput::new(2);
+        manual.reseed(10);
+        assert_eq!(seeded.pop(), manual.pop());
+    }
+
+    #[test]
+    fn test_vdcorput_iterator_take() {
+        let vals: Vec<f64> = VdCorput::seeded(2, 0).take(3).collect();
+        assert_eq!(vals, vec![vdc(1, 2), vdc(2, 2), vdc(3, 2)]);
+    }
+
+    #[test]
+    fn test_halton_seeded_matches_new_then_reseed() {
+        let mut seeded = Halton::seeded(2, 3, 10);
+        let mut manual = Halton::new(2, 3);
+        manual.reseed(10);
+        assert_eq!(seeded.pop(), manual.pop());
+    }
+
+    #[test]
+    fn test_halton_iterator_take() {
+        let vals: Vec<[f64; 2]> = Halton::seeded(2, 3, 0).take(3).collect();
+        assert_eq!(vals.len(), 3);
+    }
+
+    #[test]
+    fn test_circle_seeded_matches_new_then_reseed() {
+        let mut seeded = Circle::seeded(2, 10);
+        let mut manual = Circle::new(2);
+        manual.reseed(10);
+        assert_eq!(seeded.pop(), manual.pop());
+    }
+
+    #[test]
+    fn test_circle_iterator_take() {
+        let vals: Vec<[f64; 2]> = Circle::seeded(2, 0).take(3).collect();
+        assert_eq!(vals.len(), 3);
+    }
+
+    #[test]
+    fn test_sphere_seeded_matches_new_then_reseed() {
+        let mut seeded = Sphere::seeded(&[2, 3], 10);
+        let mut manual = Sphere::new(&[2, 3]);
+        manual.reseed(10);
+        assert_eq!(seeded.pop(), manual.pop());
+    }
+
+    #[test]
+    fn test_sphere_iterator_take() {
+        let vals: Vec<[f64; 3]> = Sphere::seeded(&[2, 3], 0).take(3).collect();
+        assert_eq!(vals.len(), 3);
+    }
+
+    #[test]
+    fn test_sphere3hopf_seeded_matches_new_then_reseed() {
+        let mut seeded = Sphere3Hopf::seeded(&[2, 3, 5], 10);
+        let mut manual = Sphere3Hopf::new(&[2, 3, 5]);
+        manual.reseed(10);
+        assert_eq!(seeded.pop(), manual.pop());
+    }
+
+    #[test]
+    fn test_sphere3hopf_iterator_take() {
+        let vals: Vec<[f64; 4]> = Sphere3Hopf::seeded(&[2, 3, 5], 0).take(3).collect();
+        assert_eq!(vals.len(), 3);
+    }
 }
@@ -1,3 +1,7 @@
+// Legacy/unreachable: predates the VdCorput/Circle API refactor (references the
+// no-longer-existing `Vdcorput` type) and isn't wired into
+// `lib.rs`'s module tree. See the `// pub mod lds_n;` note in lib.rs. Left as-is rather
+// than revived; not in scope for the generators' `serde` checkpoint/resume support.
 // use ndarray::{array, Array, Array2, Array1};
 // use interp::interp;
 // use ndarray::Array1;
@@ -1,10 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod mathops;
+mod splitmix;
+
+pub mod cvt;
+pub mod discrepancy;
 pub mod ilds;
 pub mod lds;
+pub mod sphere_n;
+// lds_n.rs/lds_n1.rs/lds_n2.rs (including the `CylinN` variant tree) predate the
+// VdCorput/Circle/HaltonN API this crate settled on and don't compile against it; they're
+// legacy/unreachable and intentionally left out of the module tree and out of scope for
+// the `serde` checkpoint/resume support added to the generators above.
 // pub mod lds_n;
 
 pub use crate::lds::HaltonN;
 pub use crate::lds::PRIME_TABLE;
-pub use crate::lds::{Circle, Disk, Halton, Sphere, Sphere3Hopf, VdCorput};
+pub use crate::lds::{Circle, Halton, Sphere, Sphere3Hopf, VdCorput};
+pub use crate::lds::{ScrambleMode, ScrambledHaltonN, ScrambledVdCorput};
+pub use crate::lds::HaltonConst;
+pub use crate::lds::Hammersley;
+pub use crate::ilds::{Halton64, VdCorput64};
 
 #[cfg(test)]
 mod tests {
@@ -30,17 +49,9 @@ mod tests {
             println!("{:?}", cgen.pop());
         }
         let res = cgen.pop();
-        assert_approx_eq!(res[1], -0.8314696123025452);
-
-        let mut dgen = Disk::new(&[2, 3]);
-        dgen.reseed(0);
-        for _i in 0..10 {
-            println!("{:?}", dgen.pop());
-        }
-        let res = dgen.pop();
-        assert_approx_eq!(res[0], 0.32102183949750684);
+        assert_approx_eq!(res[1], -0.5555702330196022);
 
-        let mut hgen = Halton::new(&[2, 3]);
+        let mut hgen = Halton::new(2, 3);
         hgen.reseed(10);
         for _i in 0..10 {
             println!("{:?}", hgen.pop());
@@ -54,7 +65,7 @@ mod tests {
             println!("{:?}", sgen.pop());
         }
         let res = sgen.pop();
-        assert_approx_eq!(res[1], 0.8722297870746605);
+        assert_approx_eq!(res[1], 0.37624320397808186);
 
         let mut s3fgen = Sphere3Hopf::new(&base);
         s3fgen.reseed(10);
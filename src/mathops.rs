@@ -0,0 +1,82 @@
+//! Transcendental math helpers that route through either `std` or `libm`.
+//!
+//! This crate can run without `std` (e.g. on bare-metal or `wasm32-unknown-unknown`
+//! targets) by enabling the `libm` feature instead of the default `std` feature.
+//! All sine/cosine/sqrt/pow calls in the generators go through this module so the
+//! two backends stay interchangeable.
+
+#[cfg(feature = "std")]
+pub const PI: f64 = std::f64::consts::PI;
+#[cfg(feature = "std")]
+pub const TAU: f64 = std::f64::consts::TAU;
+
+#[cfg(not(feature = "std"))]
+pub const PI: f64 = core::f64::consts::PI;
+#[cfg(not(feature = "std"))]
+pub const TAU: f64 = core::f64::consts::TAU;
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn cos(x: f64) -> f64 {
+    x.cos()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn sin(x: f64) -> f64 {
+    x.sin()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn powi(x: f64, n: i32) -> f64 {
+    x.powi(n)
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn powi(x: f64, n: i32) -> f64 {
+    libm::pow(x, n as f64)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn acos(x: f64) -> f64 {
+    x.acos()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn acos(x: f64) -> f64 {
+    libm::acos(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn floor(x: f64) -> f64 {
+    x.floor()
+}
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn floor(x: f64) -> f64 {
+    libm::floor(x)
+}
@@ -13,8 +13,25 @@
 //! This module implements sphere generators for arbitrary dimensions using
 //! low-discrepancy sequences.
 
+use crate::lds::vdc;
+use crate::mathops::{acos, cos, powi, sin, sqrt, PI, TAU};
 use crate::VdCorput;
-use std::f64::consts::PI;
+
+#[cfg(feature = "std")]
+use std::{boxed::Box, sync::Mutex, vec, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use spin::Mutex;
+
+#[cfg(feature = "std")]
+use once_cell::sync::Lazy;
+#[cfg(not(feature = "std"))]
+use spin::Lazy;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Simple implementation of numpy.linspace
 fn linspace(start: f64, stop: f64, num: usize) -> Vec<f64> {
@@ -57,8 +74,8 @@ struct SphereTables {
 impl SphereTables {
     fn new() -> Self {
         let x = linspace(0.0, PI, 300);
-        let neg_cosine = x.iter().map(|&x| -x.cos()).collect();
-        let sine = x.iter().map(|&x| x.sin()).collect();
+        let neg_cosine = x.iter().map(|&x| -cos(x)).collect();
+        let sine = x.iter().map(|&x| sin(x)).collect();
         let f2 = x
             .iter()
             .zip(&neg_cosine)
@@ -88,17 +105,16 @@ impl SphereTables {
 }
 
 /// Thread-safe cached sphere tables
-static SPHERE_TABLES: once_cell::sync::Lazy<SphereTables> =
-    once_cell::sync::Lazy::new(SphereTables::new);
+static SPHERE_TABLES: Lazy<SphereTables> = Lazy::new(SphereTables::new);
 
 /// Calculates the table-lookup of the mapping function for n
 fn get_tp(n: usize) -> Vec<f64> {
-    use once_cell::sync::Lazy;
-    use std::sync::Mutex;
-
     static TP_CACHE: Lazy<Mutex<Vec<Vec<f64>>>> = Lazy::new(|| Mutex::new(Vec::new()));
 
+    #[cfg(feature = "std")]
     let mut cache = TP_CACHE.lock().unwrap();
+    #[cfg(not(feature = "std"))]
+    let mut cache = TP_CACHE.lock();
 
     // If already computed, return a copy
     if n < cache.len() {
@@ -123,7 +139,7 @@ fn get_tp(n: usize) -> Vec<f64> {
                 .enumerate()
                 .map(|(i, _xi)| {
                     ((new_n - 1) as f64 * tp_minus2[i]
-                        + neg_cosine[i] * sine[i].powi((new_n - 1) as i32))
+                        + neg_cosine[i] * powi(sine[i], (new_n - 1) as i32))
                         / new_n as f64
                 })
                 .collect()
@@ -141,28 +157,83 @@ pub trait SphereGen {
 
     /// Reseeds the generator with a new seed
     fn reseed(&mut self, seed: u32);
+
+    /// Writes the next point's coordinates into `out` in place, without allocating.
+    ///
+    /// `out` must have exactly as many slots as the generator's dimension; implementations assert
+    /// this. This is the allocation-free counterpart of [`Self::pop`], meant for tight sampling
+    /// loops that reuse a single buffer.
+    fn pop_into(&mut self, out: &mut [f64]);
+
+    /// Computes the `k`-th point (1-indexed, relative to the last `reseed`) directly, without
+    /// mutating the generator. This is the same point `pop()` would return on its `k`-th call,
+    /// which makes the sequence randomly addressable and safe to fan out across threads.
+    fn point_at(&self, k: usize) -> Vec<f64>;
+
+    /// Fills a `Vec` with the first `m` points, computed in parallel via [`Self::point_at`].
+    ///
+    /// This is safe because `point_at` never mutates the generator.
+    #[cfg(feature = "rayon")]
+    fn batch(&self, m: usize) -> Vec<Vec<f64>>
+    where
+        Self: Sync,
+    {
+        use rayon::prelude::*;
+
+        (0..m).into_par_iter().map(|k| self.point_at(k + 1)).collect()
+    }
+}
+
+/// A [`SphereGen`] whose output dimension is known at compile time.
+///
+/// This lets callers receive points as fixed-size arrays instead of heap-allocated
+/// `Vec<f64>`s, keeping the dimensionality in the type system and avoiding an
+/// allocation per point.
+pub trait SphereGenArray<const D: usize> {
+    /// Generates and returns the next point as a `[f64; D]`.
+    fn pop_array(&mut self) -> [f64; D];
 }
 
 /// Wrapper for Sphere that implements SphereGen trait
 struct SphereWrapper {
     sphere: crate::Sphere,
+    bases: [u32; 2],
+    seed: usize,
 }
 
 impl SphereWrapper {
     fn new(base: [u32; 2]) -> Self {
         Self {
-            sphere: crate::Sphere::new(base),
+            sphere: crate::Sphere::new(&[base[0] as usize, base[1] as usize]),
+            bases: base,
+            seed: 0,
         }
     }
 }
 
 impl SphereGen for SphereWrapper {
     fn pop(&mut self) -> Vec<f64> {
-        self.sphere.pop().to_vec()
+        let mut out = vec![0.0; 3];
+        self.pop_into(&mut out);
+        out
+    }
+
+    fn pop_into(&mut self, out: &mut [f64]) {
+        assert_eq!(out.len(), 3);
+        out.copy_from_slice(&self.sphere.pop());
     }
 
     fn reseed(&mut self, seed: u32) {
-        self.sphere.reseed(seed);
+        self.sphere.reseed(seed as usize);
+        self.seed = seed as usize;
+    }
+
+    fn point_at(&self, k: usize) -> Vec<f64> {
+        let idx = self.seed + k;
+        let cosphi = 2.0 * vdc(idx, self.bases[0] as usize) - 1.0; // map to [-1, 1];
+        let sinphi = sqrt(1.0 - cosphi * cosphi);
+        let theta = vdc(idx, self.bases[1] as usize) * TAU;
+        vec![sinphi * sin(theta), sinphi * cos(theta), cosphi]
     }
 }
 
@@ -171,18 +242,21 @@ impl SphereGen for SphereWrapper {
 /// # Examples
 ///
 /// ```
-/// use lds_gen::sphere_n::{Sphere3, SphereGen};
+/// use lds_rs::sphere_n::{Sphere3, SphereGen};
 /// let mut sgen = Sphere3::new(&[2, 3, 5]);
 /// sgen.reseed(0);
 /// let point = sgen.pop();
 /// assert_eq!(point.len(), 4);
 /// ```
 pub struct Sphere3 {
+    #[allow(dead_code)]
+    bases: [u32; 3],
     vdc: VdCorput,
     sphere2: SphereWrapper,
     half_pi: f64,
     x: Vec<f64>,
     f2: Vec<f64>,
+    seed: usize,
 }
 
 impl Sphere3 {
@@ -195,23 +269,63 @@ impl Sphere3 {
         assert!(base.len() >= 3, "Sphere3 requires at least 3 bases");
         let tables = SPHERE_TABLES.get();
         Self {
-            vdc: VdCorput::new(base[0]),
+            bases: [base[0], base[1], base[2]],
+            vdc: VdCorput::new(base[0] as usize),
             sphere2: SphereWrapper::new([base[1], base[2]]),
             half_pi: tables.4,
             x: tables.0.to_vec(),
             f2: tables.3.to_vec(),
+            seed: 0,
         }
     }
 }
 
+/// Compact, reconstructible checkpoint of a [`Sphere3`] generator: the bases
+/// plus the shared position of every nested `VdCorput` in the chain (they all
+/// advance in lockstep, one `pop()` per level per call).
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct Sphere3Snapshot {
+    bases: [u32; 3],
+    position: usize,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Sphere3 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Sphere3Snapshot {
+            bases: self.bases,
+            position: self.vdc.position(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Sphere3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let snapshot = Sphere3Snapshot::deserialize(deserializer)?;
+        let mut sphere = Sphere3::new(&snapshot.bases);
+        sphere.reseed(snapshot.position as u32);
+        Ok(sphere)
+    }
+}
+
 impl SphereGen for Sphere3 {
-    fn pop(&mut self) -> Vec<f64> {
-        let ti = self.half_pi * self.vdc.pop(); // map to [t0, tm-1]
+    fn point_at(&self, k: usize) -> Vec<f64> {
+        let idx = self.seed + k;
+        let ti = self.half_pi * self.vdc.nth(idx); // map to [t0, tm-1]
         let xi = simple_interp(ti, &self.f2, &self.x);
-        let cosxi = xi.cos();
-        let sinxi = xi.sin();
+        let cosxi = cos(xi);
+        let sinxi = sin(xi);
 
-        let sphere2_point = self.sphere2.pop();
+        let sphere2_point = self.sphere2.point_at(k);
         let mut result = Vec::with_capacity(4);
         for &s in &sphere2_point {
             result.push(sinxi * s);
@@ -220,9 +334,231 @@ impl SphereGen for Sphere3 {
         result
     }
 
+    fn pop(&mut self) -> Vec<f64> {
+        let mut out = vec![0.0; 4];
+        self.pop_into(&mut out);
+        out
+    }
+
+    fn pop_into(&mut self, out: &mut [f64]) {
+        assert_eq!(out.len(), 4);
+        let ti = self.half_pi * self.vdc.pop(); // map to [t0, tm-1]
+        let xi = simple_interp(ti, &self.f2, &self.x);
+        let cosxi = cos(xi);
+        let sinxi = sin(xi);
+
+        let len = out.len();
+        self.sphere2.pop_into(&mut out[..len - 1]);
+        for s in out[..len - 1].iter_mut() {
+            *s *= sinxi;
+        }
+        out[len - 1] = cosxi;
+    }
+
     fn reseed(&mut self, seed: u32) {
-        self.vdc.reseed(seed);
+        self.vdc.reseed(seed as usize);
         self.sphere2.reseed(seed);
+        self.seed = seed as usize;
+    }
+}
+
+impl SphereGenArray<4> for Sphere3 {
+    fn pop_array(&mut self) -> [f64; 4] {
+        let mut out = [0.0; 4];
+        self.pop_into(&mut out);
+        out
+    }
+}
+
+impl Sphere3 {
+    /// Generates the next point as a `glam::DVec4`, for graphics code that wants
+    /// to use it without a conversion hop.
+    #[cfg(feature = "glam")]
+    pub fn pop_glam(&mut self) -> glam::DVec4 {
+        self.pop_array().into()
+    }
+
+    /// Generates the next point as a `cgmath::Vector4<f64>`, for graphics code
+    /// that wants to use it without a conversion hop.
+    #[cfg(feature = "cgmath")]
+    pub fn pop_cgmath(&mut self) -> cgmath::Vector4<f64> {
+        self.pop_array().into()
+    }
+}
+
+/// Uniform random rotation generator on SO(3), emitted as unit quaternions.
+///
+/// Drives a 3-dimensional Halton stream `u1, u2, u3 ∈ [0, 1)` through Shoemake's
+/// map:
+///
+/// ```text
+/// q = ( sqrt(1 - u1) sin(2π u2), sqrt(1 - u1) cos(2π u2),
+///       sqrt(u1) sin(2π u3),     sqrt(u1) cos(2π u3) )
+/// ```
+///
+/// which is uniform under the Haar measure on SO(3), unlike naively sampling
+/// Euler angles (which clusters samples near the poles).
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::sphere_n::Rotation3;
+/// let mut rgen = Rotation3::new(&[2, 3, 5]);
+/// rgen.reseed(0);
+/// let q = rgen.pop();
+/// assert_eq!(q.len(), 4);
+/// ```
+pub struct Rotation3 {
+    halton: crate::HaltonN,
+}
+
+impl Rotation3 {
+    /// Creates a new rotation generator
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - Array of at least 3 integers used as bases for the underlying Halton stream
+    pub fn new(base: &[usize]) -> Self {
+        assert!(base.len() >= 3, "Rotation3 requires at least 3 bases");
+        Self {
+            halton: crate::HaltonN::new(&base[0..3]),
+        }
+    }
+
+    /// Generates the next uniformly distributed unit quaternion `[x, y, z, w]`
+    pub fn pop(&mut self) -> [f64; 4] {
+        let u = self.halton.pop_vec();
+        let r1 = sqrt(1.0 - u[0]);
+        let r2 = sqrt(u[0]);
+        let theta1 = TAU * u[1];
+        let theta2 = TAU * u[2];
+
+        let mut q = [
+            r1 * sin(theta1),
+            r1 * cos(theta1),
+            r2 * sin(theta2),
+            r2 * cos(theta2),
+        ];
+
+        // The formula guarantees unit norm analytically; guard against float drift.
+        let norm = sqrt(q.iter().map(|x| x * x).sum());
+        if norm > 0.0 && (norm - 1.0).abs() > f64::EPSILON {
+            for x in q.iter_mut() {
+                *x /= norm;
+            }
+        }
+        q
+    }
+
+    /// Resets the state of the sequence generator to a specific seed value
+    pub fn reseed(&mut self, seed: usize) {
+        self.halton.reseed(seed);
+    }
+
+    /// Converts a unit quaternion `[x, y, z, w]` into its equivalent 3x3 rotation matrix
+    pub fn to_rotation_matrix(q: [f64; 4]) -> [[f64; 3]; 3] {
+        let [x, y, z, w] = q;
+        [
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - z * w),
+                2.0 * (x * z + y * w),
+            ],
+            [
+                2.0 * (x * y + z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - x * w),
+            ],
+            [
+                2.0 * (x * z - y * w),
+                2.0 * (y * z + x * w),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ]
+    }
+}
+
+/// Saff-Kuijlaars generalized-spiral sphere point generator
+///
+/// Unlike [`Sphere`](crate::Sphere) (which maps a Van der Corput coordinate to latitude and
+/// a [`Circle`](crate::Circle) value to longitude, leaving visible clustering near the
+/// poles for small point counts), [`SphereSpiral`] distributes a known, fixed number `n` of
+/// points along a spiral path for near-uniform, minimal-energy-like sphere coverage without
+/// CVT iteration.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::sphere_n::SphereSpiral;
+/// let mut sgen = SphereSpiral::new(100);
+/// let point = sgen.pop();
+/// assert_eq!(point.len(), 3);
+/// ```
+pub struct SphereSpiral {
+    n: usize,
+    count: usize,
+    phi: f64,
+}
+
+impl SphereSpiral {
+    /// Creates a new [`SphereSpiral`] generator for a fixed point count `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n < 2`, since the recurrence below divides by `n - 1`.
+    pub fn new(n: usize) -> Self {
+        assert!(n >= 2, "SphereSpiral requires at least 2 points");
+        Self { n, count: 0, phi: 0.0 }
+    }
+
+    /// Generates the next point `[x, y, z]` on the unit sphere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `n` points have been requested since the last `reseed`.
+    pub fn pop(&mut self) -> [f64; 3] {
+        self.count += 1;
+        assert!(
+            self.count <= self.n,
+            "SphereSpiral: requested more points ({}) than the set size (n = {})",
+            self.count,
+            self.n
+        );
+
+        let k = self.count;
+        let h = -1.0 + 2.0 * (k - 1) as f64 / (self.n - 1) as f64;
+        let theta = acos(h);
+
+        if k == 1 || k == self.n {
+            self.phi = 0.0;
+        } else {
+            self.phi = (self.phi + 3.6 / sqrt(self.n as f64) / sqrt(1.0 - h * h)) % TAU;
+        }
+
+        let sin_theta = sin(theta);
+        [
+            sin_theta * cos(self.phi),
+            sin_theta * sin(self.phi),
+            cos(theta),
+        ]
+    }
+
+    /// Resets the state of the sequence generator.
+    ///
+    /// Because `phi` is accumulated via a stateful recurrence rather than computed
+    /// from `seed` alone, only `seed == 0` (rewind to the start of the spiral) is
+    /// supported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seed != 0`.
+    pub fn reseed(&mut self, seed: usize) {
+        assert_eq!(
+            seed, 0,
+            "SphereSpiral::reseed only supports rewinding to 0 (the recurrence is stateful)"
+        );
+        self.count = 0;
+        self.phi = 0.0;
     }
 }
 
@@ -231,19 +567,22 @@ impl SphereGen for Sphere3 {
 /// # Examples
 ///
 /// ```
-/// use lds_gen::sphere_n::{SphereN, SphereGen};
+/// use lds_rs::sphere_n::{SphereN, SphereGen};
 /// let mut sgen = SphereN::new(&[2, 3, 5, 7]);
 /// sgen.reseed(0);
 /// let point = sgen.pop();
 /// assert_eq!(point.len(), 5); // 4 bases produce 5D point
 /// ```
 pub struct SphereN {
+    #[allow(dead_code)]
+    bases: Vec<u32>,
     vdc: VdCorput,
-    s_gen: Box<dyn SphereGen>,
+    s_gen: Box<dyn SphereGen + Sync>,
     n: usize,
     tp: Vec<f64>,
     tp_start: f64,
     range: f64,
+    seed: usize,
 }
 
 impl SphereN {
@@ -257,9 +596,9 @@ impl SphereN {
         let n = base.len() - 1;
         assert!(n >= 2, "SphereN requires at least 3 bases (n >= 2)");
 
-        let vdc = VdCorput::new(base[0]);
+        let vdc = VdCorput::new(base[0] as usize);
 
-        let s_gen: Box<dyn SphereGen> = if n == 2 {
+        let s_gen: Box<dyn SphereGen + Sync> = if n == 2 {
             Box::new(SphereWrapper::new([base[1], base[2]]))
         } else {
             Box::new(SphereN::new(&base[1..]))
@@ -270,26 +609,70 @@ impl SphereN {
         let range = tp[tp.len() - 1] - tp_start;
 
         Self {
+            bases: base.to_vec(),
             vdc,
             s_gen,
             n,
             tp,
             tp_start,
             range,
+            seed: 0,
         }
     }
 }
 
+/// Compact, reconstructible checkpoint of a [`SphereN`] generator. The recursive
+/// `s_gen` chain can't derive `Serialize`/`Deserialize` directly (it's a
+/// `Box<dyn SphereGen + Sync>`), but every nested `VdCorput` in the chain shares the
+/// same position (each level advances by exactly one `pop()` per outer
+/// `pop()`), so the bases plus that single position are enough to resume the
+/// whole chain bit-for-bit via [`SphereN::new`] + [`SphereN::reseed`].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SphereNSnapshot {
+    bases: Vec<u32>,
+    position: usize,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for SphereN {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SphereNSnapshot {
+            bases: self.bases.clone(),
+            position: self.vdc.position(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for SphereN {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let snapshot = SphereNSnapshot::deserialize(deserializer)?;
+        let mut sphere = SphereN::new(&snapshot.bases);
+        sphere.reseed(snapshot.position as u32);
+        Ok(sphere)
+    }
+}
+
 impl SphereGen for SphereN {
-    fn pop(&mut self) -> Vec<f64> {
+    fn point_at(&self, k: usize) -> Vec<f64> {
+        let idx = self.seed + k;
+
         if self.n == 2 {
             let tables = SPHERE_TABLES.get();
-            let ti = tables.4 * self.vdc.pop(); // map to [t0, tm-1]
+            let ti = tables.4 * self.vdc.nth(idx); // map to [t0, tm-1]
             let xi = simple_interp(ti, tables.3, tables.0);
-            let cosxi = xi.cos();
-            let sinxi = xi.sin();
+            let cosxi = cos(xi);
+            let sinxi = sin(xi);
 
-            let sphere_point = self.s_gen.pop();
+            let sphere_point = self.s_gen.point_at(k);
             let mut result = Vec::with_capacity(sphere_point.len() + 1);
             for &s in &sphere_point {
                 result.push(sinxi * s);
@@ -298,23 +681,119 @@ impl SphereGen for SphereN {
             return result;
         }
 
-        let vd = self.vdc.pop();
+        let vd = self.vdc.nth(idx);
         let ti = self.tp_start + self.range * vd; // map to [t0, tm-1]
         let xi = simple_interp(ti, &self.tp, &SPHERE_TABLES.x);
-        let sinphi = xi.sin();
+        let sinphi = sin(xi);
 
-        let sphere_point = self.s_gen.pop();
+        let sphere_point = self.s_gen.point_at(k);
         let mut result = Vec::with_capacity(sphere_point.len() + 1);
         for &s in &sphere_point {
             result.push(s * sinphi);
         }
-        result.push(xi.cos());
+        result.push(cos(xi));
         result
     }
 
+    fn pop(&mut self) -> Vec<f64> {
+        let mut out = vec![0.0; self.n + 2];
+        self.pop_into(&mut out);
+        out
+    }
+
+    fn pop_into(&mut self, out: &mut [f64]) {
+        assert_eq!(out.len(), self.n + 2);
+
+        if self.n == 2 {
+            let tables = SPHERE_TABLES.get();
+            let ti = tables.4 * self.vdc.pop(); // map to [t0, tm-1]
+            let xi = simple_interp(ti, tables.3, tables.0);
+            let cosxi = cos(xi);
+            let sinxi = sin(xi);
+
+            let len = out.len();
+            self.s_gen.pop_into(&mut out[..len - 1]);
+            for s in out[..len - 1].iter_mut() {
+                *s *= sinxi;
+            }
+            out[len - 1] = cosxi;
+            return;
+        }
+
+        let vd = self.vdc.pop();
+        let ti = self.tp_start + self.range * vd; // map to [t0, tm-1]
+        let xi = simple_interp(ti, &self.tp, &SPHERE_TABLES.x);
+        let sinphi = sin(xi);
+
+        let len = out.len();
+        self.s_gen.pop_into(&mut out[..len - 1]);
+        for s in out[..len - 1].iter_mut() {
+            *s *= sinphi;
+        }
+        out[len - 1] = cos(xi);
+    }
+
     fn reseed(&mut self, seed: u32) {
-        self.vdc.reseed(seed);
+        self.vdc.reseed(seed as usize);
         self.s_gen.reseed(seed);
+        self.seed = seed as usize;
+    }
+}
+
+/// A [`SphereN`] wrapped to expose points as a fixed-size `[f64; D]` instead of
+/// `Vec<f64>`.
+///
+/// `SphereN`'s dimension is chosen recursively at runtime (one `Box<dyn SphereGen +
+/// Sync>` level per remaining base), so it can't be restructured as a true
+/// allocation-free const-generic type the way [`crate::lds::HaltonConst`] can. Instead,
+/// [`SphereConst`] keeps one `SphereN` plus one reusable scratch buffer allocated at
+/// construction time, and uses [`SphereGen::pop_into`] to fill that buffer on every
+/// `pop()` instead of allocating a fresh `Vec<f64>` per point.
+///
+/// # Examples
+///
+/// ```
+/// use lds_rs::sphere_n::SphereConst;
+///
+/// let mut sgen = SphereConst::<5>::new(&[2, 3, 5, 7]);
+/// sgen.reseed(0);
+/// let point = sgen.pop();
+/// assert_eq!(point.len(), 5);
+/// ```
+pub struct SphereConst<const D: usize> {
+    inner: SphereN,
+    scratch: Vec<f64>,
+}
+
+impl<const D: usize> SphereConst<D> {
+    /// Creates a new [`SphereConst`]. `base` must have exactly `D - 1` entries, matching
+    /// [`SphereN::new`]'s `n + 2`-length output (`n = base.len() - 1`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base.len() != D - 1`.
+    pub fn new(base: &[u32]) -> Self {
+        assert_eq!(
+            base.len() + 1,
+            D,
+            "SphereConst::<D>::new requires exactly D - 1 bases"
+        );
+        Self {
+            inner: SphereN::new(base),
+            scratch: vec![0.0; D],
+        }
+    }
+
+    /// Generates the next point as a `[f64; D]`, reusing the internal scratch buffer
+    /// instead of allocating.
+    pub fn pop(&mut self) -> [f64; D] {
+        self.inner.pop_into(&mut self.scratch);
+        core::array::from_fn(|i| self.scratch[i])
+    }
+
+    /// Resets the state of the underlying [`SphereN`] to a specific seed value.
+    pub fn reseed(&mut self, seed: u32) {
+        self.inner.reseed(seed);
     }
 }
 
@@ -326,7 +805,7 @@ mod tests {
     #[test]
     fn test_linspace() {
         let result = linspace(0.0, 1.0, 5);
-        let expected = vec![0.0, 0.25, 0.5, 0.75, 1.0];
+        let expected = [0.0, 0.25, 0.5, 0.75, 1.0];
         assert_eq!(result.len(), 5);
         for i in 0..5 {
             assert_relative_eq!(result[i], expected[i], epsilon = 1e-10);
@@ -336,7 +815,7 @@ mod tests {
         assert_eq!(result, vec![0.0]);
 
         let result = linspace(-1.0, 1.0, 3);
-        let expected = vec![-1.0, 0.0, 1.0];
+        let expected = [-1.0, 0.0, 1.0];
         for i in 0..3 {
             assert_relative_eq!(result[i], expected[i], epsilon = 1e-10);
         }
@@ -391,7 +870,7 @@ mod tests {
         assert_relative_eq!(radius_sq, 1.0, epsilon = 1e-10);
 
         for &coord in &point {
-            assert!(-1.0 <= coord && coord <= 1.0);
+            assert!((-1.0..=1.0).contains(&coord));
         }
     }
 
@@ -500,16 +979,16 @@ mod tests {
     #[test]
     fn test_comparison_with_python() {
         // Expected values from Python doctest examples
-        let expected_sphere3 = vec![
-            0.2913440162992141,
+        let expected_sphere3 = [
             0.8966646826186098,
+            0.2913440162992141,
             -0.33333333333333337,
             6.123233995736766e-17,
         ];
 
-        let expected_spheren = vec![
-            0.4809684718990214,
+        let expected_spheren = [
             0.6031153874276115,
+            0.4809684718990214,
             -0.5785601510223212,
             0.2649326520763179,
             6.123233995736766e-17,
@@ -543,4 +1022,255 @@ mod tests {
     fn test_spheren_insufficient_bases() {
         SphereN::new(&[2, 3]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sphere3_serde_round_trip_resumes_mid_stream() {
+        let mut sgen = Sphere3::new(&[2, 3, 5]);
+        sgen.reseed(0);
+        for _ in 0..6 {
+            sgen.pop();
+        }
+
+        let snapshot = serde_json::to_string(&sgen).unwrap();
+        let mut resumed: Sphere3 = serde_json::from_str(&snapshot).unwrap();
+
+        for _ in 0..4 {
+            assert_eq!(sgen.pop(), resumed.pop());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_spheren_serde_round_trip_resumes_mid_stream() {
+        let mut sgen = SphereN::new(&[2, 3, 5, 7, 11]);
+        sgen.reseed(0);
+        for _ in 0..6 {
+            sgen.pop();
+        }
+
+        let snapshot = serde_json::to_string(&sgen).unwrap();
+        let mut resumed: SphereN = serde_json::from_str(&snapshot).unwrap();
+
+        for _ in 0..4 {
+            assert_eq!(sgen.pop(), resumed.pop());
+        }
+    }
+
+    #[test]
+    fn test_sphere3_point_at_matches_sequential_pop() {
+        let mut sgen = Sphere3::new(&[2, 3, 5]);
+        sgen.reseed(0);
+
+        for k in 1..=5 {
+            let popped = sgen.pop();
+            let addressed = sgen.point_at(k);
+            assert_eq!(popped, addressed);
+        }
+    }
+
+    #[test]
+    fn test_spheren_point_at_matches_sequential_pop() {
+        let mut sgen = SphereN::new(&[2, 3, 5, 7, 11]);
+        sgen.reseed(0);
+
+        for k in 1..=5 {
+            let popped = sgen.pop();
+            let addressed = sgen.point_at(k);
+            assert_eq!(popped, addressed);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_spheren_batch_matches_sequential_pop() {
+        let mut sgen = SphereN::new(&[2, 3, 5, 7]);
+        sgen.reseed(0);
+
+        let sequential: Vec<_> = (0..20).map(|_| sgen.pop()).collect();
+        let batched = sgen.batch(20);
+
+        assert_eq!(sequential, batched);
+    }
+
+    #[test]
+    fn test_spheren_pop_into_matches_pop() {
+        let mut sgen = SphereN::new(&[2, 3, 5, 7]);
+        let mut sgen_into = SphereN::new(&[2, 3, 5, 7]);
+        sgen.reseed(0);
+        sgen_into.reseed(0);
+
+        let mut out = vec![0.0; 5];
+        for _ in 0..10 {
+            let popped = sgen.pop();
+            sgen_into.pop_into(&mut out);
+            assert_eq!(popped, out);
+        }
+    }
+
+    #[test]
+    fn test_sphereconst_matches_spheren() {
+        let mut sgen = SphereN::new(&[2, 3, 5, 7]);
+        let mut cgen = SphereConst::<5>::new(&[2, 3, 5, 7]);
+        sgen.reseed(0);
+        cgen.reseed(0);
+
+        for _ in 0..10 {
+            let expected = sgen.pop();
+            let actual = cgen.pop();
+            assert_eq!(expected, actual.to_vec());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "requires exactly D - 1 bases")]
+    fn test_sphereconst_rejects_wrong_base_count() {
+        let _ = SphereConst::<5>::new(&[2, 3, 5]);
+    }
+
+    #[test]
+    fn test_sphere3_pop_into_matches_pop() {
+        let mut sgen = Sphere3::new(&[2, 3, 5]);
+        let mut sgen_into = Sphere3::new(&[2, 3, 5]);
+        sgen.reseed(0);
+        sgen_into.reseed(0);
+
+        let mut out = vec![0.0; 4];
+        for _ in 0..10 {
+            let popped = sgen.pop();
+            sgen_into.pop_into(&mut out);
+            assert_eq!(popped, out);
+        }
+    }
+
+    #[test]
+    fn test_sphere3_pop_array_matches_pop() {
+        let mut sgen = Sphere3::new(&[2, 3, 5]);
+        let mut sgen_array = Sphere3::new(&[2, 3, 5]);
+        sgen.reseed(0);
+        sgen_array.reseed(0);
+
+        for _ in 0..10 {
+            let popped = sgen.pop();
+            let array = sgen_array.pop_array();
+            assert_eq!(popped, array.to_vec());
+        }
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_sphere3_pop_glam_matches_pop_array() {
+        let mut sgen = Sphere3::new(&[2, 3, 5]);
+        let mut sgen_glam = Sphere3::new(&[2, 3, 5]);
+        sgen.reseed(0);
+        sgen_glam.reseed(0);
+
+        let array = sgen.pop_array();
+        let vec4 = sgen_glam.pop_glam();
+        assert_eq!(vec4, glam::DVec4::from(array));
+    }
+
+    #[cfg(feature = "cgmath")]
+    #[test]
+    fn test_sphere3_pop_cgmath_matches_pop_array() {
+        let mut sgen = Sphere3::new(&[2, 3, 5]);
+        let mut sgen_cgmath = Sphere3::new(&[2, 3, 5]);
+        sgen.reseed(0);
+        sgen_cgmath.reseed(0);
+
+        let array = sgen.pop_array();
+        let vector4 = sgen_cgmath.pop_cgmath();
+        assert_eq!(vector4, cgmath::Vector4::from(array));
+    }
+
+    #[test]
+    fn test_rotation3_pop_is_unit_quaternion() {
+        let mut rgen = Rotation3::new(&[2, 3, 5]);
+        rgen.reseed(0);
+
+        for _ in 0..20 {
+            let q = rgen.pop();
+            let norm_sq: f64 = q.iter().map(|x| x * x).sum();
+            assert!((norm_sq - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_rotation3_reseed() {
+        let mut rgen = Rotation3::new(&[2, 3, 5]);
+        rgen.reseed(10);
+        let first = rgen.pop();
+
+        rgen.reseed(10);
+        let second = rgen.pop();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_rotation3_to_rotation_matrix_identity() {
+        let identity = Rotation3::to_rotation_matrix([0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(identity, [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_spherespiral_produces_unit_vectors() {
+        let mut sgen = SphereSpiral::new(50);
+        for _ in 0..50 {
+            let p = sgen.pop();
+            let norm_sq: f64 = p.iter().map(|x| x * x).sum();
+            assert!((norm_sq - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_spherespiral_first_and_last_points() {
+        let mut sgen = SphereSpiral::new(10);
+        let first = sgen.pop();
+        // h_1 = -1, so theta_1 = pi, phi_1 = 0: point is [0, 0, -1].
+        assert!((first[0]).abs() < 1e-9);
+        assert!((first[1]).abs() < 1e-9);
+        assert!((first[2] + 1.0).abs() < 1e-9);
+
+        for _ in 0..8 {
+            sgen.pop();
+        }
+        let last = sgen.pop();
+        // h_n = 1, so theta_n = 0, phi_n = 0: point is [0, 0, 1].
+        assert!((last[0]).abs() < 1e-9);
+        assert!((last[1]).abs() < 1e-9);
+        assert!((last[2] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "requested more points")]
+    fn test_spherespiral_panics_past_n() {
+        let mut sgen = SphereSpiral::new(3);
+        for _ in 0..4 {
+            sgen.pop();
+        }
+    }
+
+    #[test]
+    fn test_spherespiral_reseed_rewinds() {
+        let mut sgen = SphereSpiral::new(20);
+        let first = sgen.pop();
+        sgen.pop();
+        sgen.reseed(0);
+        let restarted = sgen.pop();
+        assert_eq!(first, restarted);
+    }
+
+    #[test]
+    #[should_panic(expected = "only supports rewinding to 0")]
+    fn test_spherespiral_reseed_rejects_nonzero() {
+        let mut sgen = SphereSpiral::new(20);
+        sgen.reseed(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 points")]
+    fn test_spherespiral_rejects_too_few_points() {
+        SphereSpiral::new(1);
+    }
 }
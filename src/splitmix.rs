@@ -0,0 +1,59 @@
+//! Internal splitmix64-style mixing and digit-permutation helpers shared by the
+//! scrambled generators in [`crate::lds`] and [`crate::ilds`], kept in one place so the
+//! two no longer drift out of sync with each other.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Mixes a 64-bit value (splitmix64-style) into a well-distributed 64-bit output.
+///
+/// Used by the scrambled generators to derive a deterministic pseudo-random
+/// permutation/offset from a digit prefix and a per-dimension key, without pulling in
+/// an external RNG dependency.
+pub(crate) fn mix64(mut x: u64) -> u64 {
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^= x >> 31;
+    x
+}
+
+/// Computes the permutation of `{0, .., base - 1}` determined by `key`, then returns
+/// where digit `d` maps to under that permutation.
+///
+/// Used by [`crate::lds`]'s `usize`-based scrambled generators.
+pub(crate) fn permute_digit(d: usize, base: usize, key: u64) -> usize {
+    let mut perm: Vec<usize> = (0..base).collect();
+    let mut state = key;
+    for i in (1..base).rev() {
+        state = mix64(state);
+        let j = (state as usize) % (i + 1);
+        perm.swap(i, j);
+    }
+    perm[d]
+}
+
+/// Computes the permutation of `{0, .., base - 1}` determined by `key`, then returns
+/// where digit `d` maps to under that permutation.
+///
+/// Used by [`crate::ilds`]'s `u32`-based scrambled generators, which bound `base` to
+/// fit a fixed-size stack buffer instead of allocating.
+pub(crate) fn permute_digit_u32(d: u32, base: u32, key: u64) -> u32 {
+    debug_assert!(
+        base as usize <= 256,
+        "base must fit in a fixed-size digit buffer"
+    );
+    let base = base as usize;
+    let mut perm: [u32; 256] = [0; 256];
+    for (i, slot) in perm[..base].iter_mut().enumerate() {
+        *slot = i as u32;
+    }
+    let mut state = key;
+    for i in (1..base).rev() {
+        state = mix64(state);
+        let j = (state as usize) % (i + 1);
+        perm.swap(i, j);
+    }
+    perm[d as usize]
+}